@@ -46,6 +46,11 @@ pub fn development_config() -> Result<ChainSpec, String> {
 		vec![authority_keys_from_seed("Alice")],
 		// Sudo account
 		get_account_id_from_seed::<sr25519::Public>("Alice"),
+		// Technical committee members
+		vec![
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			get_account_id_from_seed::<sr25519::Public>("Bob"),
+		],
 		// Pre-funded accounts
 		vec![
 			get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -72,6 +77,12 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 		vec![authority_keys_from_seed("Alice"), authority_keys_from_seed("Bob")],
 		// Sudo account
 		get_account_id_from_seed::<sr25519::Public>("Alice"),
+		// Technical committee members
+		vec![
+			get_account_id_from_seed::<sr25519::Public>("Alice"),
+			get_account_id_from_seed::<sr25519::Public>("Bob"),
+			get_account_id_from_seed::<sr25519::Public>("Charlie"),
+		],
 		// Pre-funded accounts
 		vec![
 			get_account_id_from_seed::<sr25519::Public>("Alice"),
@@ -97,6 +108,7 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 fn testnet_genesis(
 	initial_authorities: Vec<(AuraId, GrandpaId)>,
 	root_key: AccountId,
+	technical_committee: Vec<AccountId>,
 	endowed_accounts: Vec<AccountId>,
 	_enable_println: bool,
 ) -> serde_json::Value {
@@ -115,6 +127,9 @@ fn testnet_genesis(
 			// Assign network admin rights.
 			"key": Some(root_key),
 		},
+		"technicalCommittee": {
+			"members": technical_committee,
+		},
 	})
 }
 
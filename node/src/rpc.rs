@@ -8,7 +8,7 @@
 use std::sync::Arc;
 
 use jsonrpsee::RpcModule;
-use bizix_node_runtime::{opaque::Block, AccountId, Balance, Nonce};
+use bizix_node_runtime::{opaque::Block, AccountId, Balance, BlockNumber, Nonce};
 use sc_transaction_pool_api::TransactionPool;
 use sp_api::ProvideRuntimeApi;
 use sp_block_builder::BlockBuilder;
@@ -44,8 +44,8 @@ where
     C: Send + Sync + 'static,
     C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Nonce>,
     C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
-    C::Api: bizix_core_rpc::BizixRuntimeApi<Block>,
-    C::Api: pallet_company_registry_rpc::CompanyRegistryRuntimeApi<Block, AccountId, Balance>,
+    C::Api: bizix_core_rpc::BizixRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    C::Api: pallet_company_registry_rpc::CompanyRegistryRuntimeApi<Block, AccountId, Balance, BlockNumber>,
     C::Api: BlockBuilder<Block>,
     P: TransactionPool + 'static,
 {
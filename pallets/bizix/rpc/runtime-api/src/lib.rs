@@ -1,10 +1,121 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
 // Here we declare the runtime API. It is implemented it the `impl` block in
 // runtime file (the `runtime-api/src/lib.rs`)
 sp_api::decl_runtime_apis! {
-	pub trait BizixApi {
-		fn get_value() -> u32;
-		//fn get_value(at: Option<Hash>) -> u32;
+	// Versiunea 1 avea doar `get_value`; toate metodele de mai jos sunt din versiunea 2
+	#[api_version(2)]
+	pub trait BizixApi<AccountId, Balance, BlockNumber> where
+		AccountId: Codec,
+		Balance: Codec,
+		BlockNumber: Codec,
+	{
+		fn proposal_deposit() -> Balance;
+		fn application(name: Vec<u8>) -> Option<ApplicationInfo<AccountId, BlockNumber>>;
+		fn applications(start_after: Option<Vec<u8>>, limit: u32) -> Vec<ApplicationInfo<AccountId, BlockNumber>>;
+		fn proposal(proposal_id: u32) -> Option<ProposalInfo<AccountId, Balance, BlockNumber>>;
+		fn proposals_by_status(status: ProposalStatus, start_after: Option<u32>, limit: u32) -> ProposalPage<AccountId, Balance, BlockNumber>;
+		fn proposals_by_proposer(proposer: AccountId, start_after: Option<u32>, limit: u32) -> ProposalPage<AccountId, Balance, BlockNumber>;
+		fn votes(proposal_id: u32) -> Option<VoteInfo<AccountId>>;
+		fn stats() -> Stats;
+		fn audits(proposal_id: u32) -> Vec<AuditInfo<AccountId, BlockNumber>>;
 	}
-}
\ No newline at end of file
+}
+
+// Structurile de mai jos oglindesc tipurile din palet, cu câmpurile aduse la `Vec<u8>`
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ReleaseInfo<AccountId, BlockNumber> {
+	pub proposal_id: u32,
+	pub version: Vec<u8>,
+	pub ipfs_address: Vec<u8>,
+	pub template_id: u32,
+	pub approved_at: BlockNumber,
+	// Există cel puțin o atestare de audit valabilă
+	pub audited: bool,
+	pub audits: Vec<AuditInfo<AccountId, BlockNumber>>,
+}
+
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ApplicationInfo<AccountId, BlockNumber> {
+	pub name: Vec<u8>,
+	pub publisher: AccountId,
+	pub releases: Vec<ReleaseInfo<AccountId, BlockNumber>>,
+	pub latest: u32,
+	// Notele date de utilizatori tuturor versiunilor (de la 1 la 5)
+	pub rating_count: u32,
+	pub rating_total: u64,
+}
+
+// Aceeași ordine a variantelor ca `ProposalStatusEnum`, deci aceeași codificare
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum ProposalStatus {
+	New,
+	InDiscussion,
+	Approved,
+	Rejected,
+	Withdrawn,
+	Deployed,
+	Deprecated,
+	Superseded,
+	Revoked,
+}
+
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ProposalInfo<AccountId, Balance, BlockNumber> {
+	pub id: u32,
+	pub proposer: AccountId,
+	// Forma binară a CID-ului
+	pub ipfs_address: Vec<u8>,
+	pub name: Vec<u8>,
+	pub version: Vec<u8>,
+	pub template_id: u32,
+	pub status: ProposalStatus,
+	pub deposit: Balance,
+	pub voting_end: Option<BlockNumber>,
+	// Schimbările de status, fiecare cu blocul la care a avut loc
+	pub status_history: Vec<(ProposalStatus, BlockNumber)>,
+}
+
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ProposalPage<AccountId, Balance, BlockNumber> {
+	pub proposals: Vec<ProposalInfo<AccountId, Balance, BlockNumber>>,
+	// Se trimite ca `start_after` pentru pagina următoare; `None` după ultima pagină
+	pub next: Option<u32>,
+}
+
+#[derive(Encode, Decode, TypeInfo)]
+pub struct VoteInfo<AccountId> {
+	pub ayes: u32,
+	pub nays: u32,
+	pub threshold: u32,
+	// Contul și votul (`true` pentru "pentru")
+	pub voters: Vec<(AccountId, bool)>,
+}
+
+#[derive(Encode, Decode, TypeInfo)]
+pub struct Stats {
+	pub proposal_count: u32,
+	pub next_proposal_id: u32,
+	// Numărul de propuneri pentru fiecare status
+	pub proposals_by_status: Vec<(ProposalStatus, u32)>,
+	pub applications: u32,
+}
+
+#[derive(Encode, Decode, TypeInfo)]
+pub struct AuditInfo<AccountId, BlockNumber> {
+	pub auditor: AccountId,
+	// Forma binară a CID-ului raportului
+	pub report: Vec<u8>,
+	pub critical: u32,
+	pub high: u32,
+	pub medium: u32,
+	pub low: u32,
+	pub attested_at: BlockNumber,
+	pub expires_at: BlockNumber,
+	// Neexpirată și de la un auditor încă înregistrat
+	pub valid: bool,
+}
@@ -1,19 +1,308 @@
 pub use bizix_core_runtime_api::BizixApi as BizixRuntimeApi;
+use bizix_core_runtime_api::{
+	ApplicationInfo, AuditInfo, ProposalInfo, ProposalPage, ProposalStatus, Stats, VoteInfo,
+};
+use codec::Codec;
 use jsonrpsee::{
 	core::RpcResult,
 	proc_macros::rpc,
 	types::error::ErrorObject,
 };
 
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ApiRef, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_runtime::{traits::Block as BlockT};
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
+
+use serde::{Deserialize, Serialize};
 
 #[rpc(client, server)]
-pub trait BizixApi<BlockHash> {
-	#[method(name = "bizix_getValue")]
-	fn get_value(&self, at: Option<BlockHash>) -> RpcResult<u32>;
+pub trait BizixApi<BlockHash, AccountId, Balance, BlockNumber> {
+	#[method(name = "bizix_getProposalDeposit")]
+	fn get_proposal_deposit(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
+
+	#[method(name = "bizix_getApplication")]
+	fn get_application(&self, name: String, at: Option<BlockHash>) -> RpcResult<Option<ApplicationData>>;
+
+	#[method(name = "bizix_listApplications")]
+	fn list_applications(
+		&self,
+		start_after: Option<String>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<Vec<ApplicationData>>;
+
+	#[method(name = "bizix_getProposal")]
+	fn get_proposal(&self, proposal_id: u32, at: Option<BlockHash>) -> RpcResult<Option<ProposalData>>;
+
+	/// Propunerile cu statusul dat sau ale propunătorului dat; trebuie setat exact unul dintre
+	/// cele două filtre. Pentru pagina următoare se trimite `next` ca `start_after`.
+	#[method(name = "bizix_listProposals")]
+	fn list_proposals(
+		&self,
+		status: Option<String>,
+		proposer: Option<AccountId>,
+		start_after: Option<u32>,
+		limit: Option<u32>,
+		at: Option<BlockHash>,
+	) -> RpcResult<ProposalPageData>;
+
+	#[method(name = "bizix_getVotes")]
+	fn get_votes(&self, proposal_id: u32, at: Option<BlockHash>) -> RpcResult<Option<VotesData>>;
+
+	#[method(name = "bizix_getAudits")]
+	fn get_audits(&self, proposal_id: u32, at: Option<BlockHash>) -> RpcResult<Vec<AuditData>>;
+
+	#[method(name = "bizix_getStats")]
+	fn get_stats(&self, at: Option<BlockHash>) -> RpcResult<StatsData>;
+}
+
+/// Mărimea implicită a paginii pentru metodele de listare.
+const DEFAULT_PAGE_SIZE: u32 = 20;
+
+/// Prima versiune a `BizixApi` cu metodele folosite aici.
+const MIN_API_VERSION: u32 = 2;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ReleaseData {
+	pub proposal_id: u32,
+	pub version: String,
+	pub ipfs_address: String,
+	pub template_id: u32,
+	pub approved_at: u64,
+	pub audited: bool,
+	pub audits: Vec<AuditData>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AuditData {
+	pub auditor: String,
+	pub report: String,
+	pub critical: u32,
+	pub high: u32,
+	pub medium: u32,
+	pub low: u32,
+	pub attested_at: u64,
+	pub expires_at: u64,
+	/// Neexpirată și de la un auditor încă înregistrat.
+	pub valid: bool,
+}
+
+impl AuditData {
+	fn from_info<AccountId, BlockNumber>(info: AuditInfo<AccountId, BlockNumber>) -> Self
+	where
+		AccountId: std::fmt::Display,
+		BlockNumber: Into<u64>,
+	{
+		Self {
+			auditor: info.auditor.to_string(),
+			report: render_cid(&info.report),
+			critical: info.critical,
+			high: info.high,
+			medium: info.medium,
+			low: info.low,
+			attested_at: info.attested_at.into(),
+			expires_at: info.expires_at.into(),
+			valid: info.valid,
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ApplicationData {
+	pub name: String,
+	pub publisher: String,
+	pub latest_version: Option<String>,
+	pub releases: Vec<ReleaseData>,
+	pub rating_count: u32,
+	/// Nota medie dată de utilizatori, de la 1 la 5; `None` până la prima notă.
+	pub average_rating: Option<f64>,
+}
+
+impl ApplicationData {
+	fn from_info<AccountId, BlockNumber>(info: ApplicationInfo<AccountId, BlockNumber>) -> Self
+	where
+		AccountId: std::fmt::Display,
+		BlockNumber: Into<u64>,
+	{
+		let releases: Vec<ReleaseData> = info
+			.releases
+			.into_iter()
+			.map(|release| ReleaseData {
+				proposal_id: release.proposal_id,
+				version: String::from_utf8_lossy(&release.version).into_owned(),
+				ipfs_address: render_cid(&release.ipfs_address),
+				template_id: release.template_id,
+				approved_at: release.approved_at.into(),
+				audited: release.audited,
+				audits: release.audits.into_iter().map(AuditData::from_info).collect(),
+			})
+			.collect();
+
+		Self {
+			name: String::from_utf8_lossy(&info.name).into_owned(),
+			publisher: info.publisher.to_string(),
+			latest_version: releases.get(info.latest as usize).map(|r| r.version.clone()),
+			releases,
+			rating_count: info.rating_count,
+			average_rating: (info.rating_count > 0)
+				.then(|| info.rating_total as f64 / info.rating_count as f64),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StatusChangeData {
+	pub status: String,
+	pub block: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProposalData {
+	pub id: u32,
+	pub proposer: String,
+	pub ipfs_address: String,
+	pub name: String,
+	pub version: String,
+	pub template_id: u32,
+	pub status: String,
+	/// Depozitul rezervat, ca text zecimal, pentru că poate depăși un număr JSON.
+	pub deposit: String,
+	pub voting_end: Option<u64>,
+	pub status_history: Vec<StatusChangeData>,
+}
+
+impl ProposalData {
+	fn from_info<AccountId, Balance, BlockNumber>(
+		info: ProposalInfo<AccountId, Balance, BlockNumber>,
+	) -> Self
+	where
+		AccountId: std::fmt::Display,
+		Balance: std::fmt::Display,
+		BlockNumber: Into<u64>,
+	{
+		Self {
+			id: info.id,
+			proposer: info.proposer.to_string(),
+			ipfs_address: render_cid(&info.ipfs_address),
+			name: String::from_utf8_lossy(&info.name).into_owned(),
+			version: String::from_utf8_lossy(&info.version).into_owned(),
+			template_id: info.template_id,
+			status: status_name(info.status).into(),
+			deposit: info.deposit.to_string(),
+			voting_end: info.voting_end.map(Into::into),
+			status_history: info
+				.status_history
+				.into_iter()
+				.map(|(status, block)| StatusChangeData {
+					status: status_name(status).into(),
+					block: block.into(),
+				})
+				.collect(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct ProposalPageData {
+	pub proposals: Vec<ProposalData>,
+	/// Cursorul pentru pagina următoare; `None` după ultima pagină.
+	pub next: Option<u32>,
+}
+
+impl ProposalPageData {
+	fn from_page<AccountId, Balance, BlockNumber>(
+		page: ProposalPage<AccountId, Balance, BlockNumber>,
+	) -> Self
+	where
+		AccountId: std::fmt::Display,
+		Balance: std::fmt::Display,
+		BlockNumber: Into<u64>,
+	{
+		Self {
+			proposals: page.proposals.into_iter().map(ProposalData::from_info).collect(),
+			next: page.next,
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VoterData {
+	pub account: String,
+	pub aye: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct VotesData {
+	pub ayes: u32,
+	pub nays: u32,
+	pub threshold: u32,
+	pub voters: Vec<VoterData>,
+}
+
+impl VotesData {
+	fn from_info<AccountId: std::fmt::Display>(info: VoteInfo<AccountId>) -> Self {
+		Self {
+			ayes: info.ayes,
+			nays: info.nays,
+			threshold: info.threshold,
+			voters: info
+				.voters
+				.into_iter()
+				.map(|(account, aye)| VoterData { account: account.to_string(), aye })
+				.collect(),
+		}
+	}
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StatsData {
+	pub proposal_count: u32,
+	pub next_proposal_id: u32,
+	pub proposals_by_status: BTreeMap<String, u32>,
+	pub applications: u32,
+}
+
+impl From<Stats> for StatsData {
+	fn from(stats: Stats) -> Self {
+		Self {
+			proposal_count: stats.proposal_count,
+			next_proposal_id: stats.next_proposal_id,
+			proposals_by_status: stats
+				.proposals_by_status
+				.into_iter()
+				.map(|(status, count)| (status_name(status).into(), count))
+				.collect(),
+			applications: stats.applications,
+		}
+	}
+}
+
+const STATUSES: [(ProposalStatus, &str); 9] = [
+	(ProposalStatus::New, "New"),
+	(ProposalStatus::InDiscussion, "InDiscussion"),
+	(ProposalStatus::Approved, "Approved"),
+	(ProposalStatus::Rejected, "Rejected"),
+	(ProposalStatus::Withdrawn, "Withdrawn"),
+	(ProposalStatus::Deployed, "Deployed"),
+	(ProposalStatus::Deprecated, "Deprecated"),
+	(ProposalStatus::Superseded, "Superseded"),
+	(ProposalStatus::Revoked, "Revoked"),
+];
+
+fn status_name(status: ProposalStatus) -> &'static str {
+	STATUSES.iter().find(|(s, _)| *s == status).map(|(_, name)| *name).unwrap_or_default()
+}
+
+fn parse_status(name: &str) -> Option<ProposalStatus> {
+	STATUSES.iter().find(|(_, n)| *n == name).map(|(status, _)| *status)
+}
+
+/// Redă ca text un CID stocat în forma binară canonică. Octeții care nu sunt un CID binar se
+/// întorc neschimbați.
+fn render_cid(bytes: &[u8]) -> String {
+	let text = bizix_core::cid::render(bytes).unwrap_or_else(|| bytes.to_vec());
+	String::from_utf8_lossy(&text).into_owned()
 }
 
 /// A struct that implements the `BizixApi`.
@@ -31,25 +320,203 @@ impl<C, Block> BizixPallet<C, Block> {
 	}
 }
 
-impl<C, Block> BizixApiServer<<Block as BlockT>::Hash> for BizixPallet<C, Block>
+impl<C, Block> BizixPallet<C, Block>
 where
     Block: BlockT,
-    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: BizixRuntimeApi<Block>,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block>,
 {
-    fn get_value(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<u32> {
+    /// API-ul de la blocul cerut (implicit cel mai bun bloc), dacă runtime-ul de acolo are cel
+    /// puțin `MIN_API_VERSION`. Runtime-urile mai vechi nu au metodele și apelul ar eșua.
+    fn api_at<AccountId, Balance, BlockNumber>(
+        &self,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<(ApiRef<'_, C::Api>, <Block as BlockT>::Hash)>
+    where
+        C::Api: BizixRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
         let api = self.client.runtime_api();
-        let at = at.unwrap_or_else(||self.client.info().best_hash);
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let version = api
+            .api_version::<dyn BizixRuntimeApi<Block, AccountId, Balance, BlockNumber>>(at)
+            .map_err(|err| {
+                ErrorObject::owned(
+                    RUNTIME_ERROR,
+                    "Unable to query runtime API version",
+                    Some(format!("{:?}", err)),
+                )
+            })?;
+        match version {
+            Some(version) if version >= MIN_API_VERSION => Ok((api, at)),
+            _ => Err(ErrorObject::owned(
+                UNSUPPORTED_RUNTIME,
+                "Runtime does not support this method",
+                Some(format!("BizixApi version {:?}, required {}", version, MIN_API_VERSION)),
+            )),
+        }
+    }
+}
+
+impl<C, Block, AccountId, Balance, BlockNumber>
+    BizixApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber> for BizixPallet<C, Block>
+where
+    Block: BlockT,
+    C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
+    C::Api: BizixRuntimeApi<Block, AccountId, Balance, BlockNumber>,
+    AccountId: Codec + std::fmt::Display,
+    Balance: Codec + std::fmt::Display,
+    BlockNumber: Codec + Into<u64>,
+{
+    fn get_proposal_deposit(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Balance> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
 
-        //api.get_value(at).map_err(runtime_error_into_rpc_err)
-		api.get_value(at).map_err(|err| {
+        api.proposal_deposit(at).map_err(|err| {
             ErrorObject::owned(
                 RUNTIME_ERROR,
-                "Runtime error",
+                "Unable to query proposal deposit",
                 Some(format!("{:?}", err)),
             )
         })
     }
+
+    fn get_application(&self, name: String, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<ApplicationData>> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+
+        let application = api.application(at, name.into_bytes()).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query application",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(application.map(ApplicationData::from_info))
+    }
+
+    fn list_applications(
+        &self,
+        start_after: Option<String>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<Vec<ApplicationData>> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+
+        let applications = api
+            .applications(at, start_after.map(String::into_bytes), limit.unwrap_or(DEFAULT_PAGE_SIZE))
+            .map_err(|err| {
+                ErrorObject::owned(
+                    RUNTIME_ERROR,
+                    "Unable to list applications",
+                    Some(format!("{:?}", err)),
+                )
+            })?;
+
+        Ok(applications.into_iter().map(ApplicationData::from_info).collect())
+    }
+
+    fn get_proposal(&self, proposal_id: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<ProposalData>> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+
+        let proposal = api.proposal(at, proposal_id).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query proposal",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(proposal.map(ProposalData::from_info))
+    }
+
+    fn list_proposals(
+        &self,
+        status: Option<String>,
+        proposer: Option<AccountId>,
+        start_after: Option<u32>,
+        limit: Option<u32>,
+        at: Option<<Block as BlockT>::Hash>,
+    ) -> RpcResult<ProposalPageData> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE);
+
+        let page = match (status, proposer) {
+            (Some(status), None) => {
+                let status = parse_status(&status).ok_or_else(|| {
+                    ErrorObject::owned(
+                        INVALID_PARAMS,
+                        "Unknown proposal status",
+                        Some(status.clone()),
+                    )
+                })?;
+                api.proposals_by_status(at, status, start_after, limit)
+            },
+            (None, Some(proposer)) => api.proposals_by_proposer(at, proposer, start_after, limit),
+            _ => {
+                return Err(ErrorObject::owned(
+                    INVALID_PARAMS,
+                    "Exactly one of status and proposer must be set",
+                    None::<()>,
+                ))
+            },
+        }
+        .map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to list proposals",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(ProposalPageData::from_page(page))
+    }
+
+    fn get_votes(&self, proposal_id: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<VotesData>> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+
+        let votes = api.votes(at, proposal_id).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query votes",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(votes.map(VotesData::from_info))
+    }
+
+    fn get_audits(&self, proposal_id: u32, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<AuditData>> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+
+        let audits = api.audits(at, proposal_id).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query audits",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(audits.into_iter().map(AuditData::from_info).collect())
+    }
+
+    fn get_stats(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<StatsData> {
+        let (api, at) = self.api_at::<AccountId, Balance, BlockNumber>(at)?;
+
+        let stats = api.stats(at).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query stats",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(stats.into())
+    }
 }
 
-const RUNTIME_ERROR: i32 = 1;
\ No newline at end of file
+const RUNTIME_ERROR: i32 = 1;
+const INVALID_PARAMS: i32 = 2;
+// Runtime-ul de la blocul cerut nu are versiunea necesară a `BizixApi`
+const UNSUPPORTED_RUNTIME: i32 = 3;
\ No newline at end of file
@@ -0,0 +1,268 @@
+//! Identificatori de conținut IPFS (CID), parsați fără `std`.
+//!
+//! Sunt acceptate CIDv0 (`Qm...`, base58btc) și CIDv1 cu multibase base32 (`b`/`B`),
+//! base58btc (`z`) sau base16 (`f`/`F`). Codec-ul și funcția de hash trebuie să fie în
+//! listele de mai jos. În stocare se păstrează forma binară canonică: multihash-ul pentru CIDv0,
+//! respectiv `<versiune><codec><multihash>` pentru CIDv1.
+
+use sp_std::vec::Vec;
+
+/// Codec-uri (multicodec) acceptate pentru conținut.
+pub const CODEC_RAW: u64 = 0x55;
+pub const CODEC_DAG_PB: u64 = 0x70;
+pub const CODEC_DAG_CBOR: u64 = 0x71;
+const ALLOWED_CODECS: [u64; 3] = [CODEC_RAW, CODEC_DAG_PB, CODEC_DAG_CBOR];
+
+/// Funcții de hash (multihash) acceptate, toate cu rezumat de 32 de octeți.
+pub const HASH_SHA2_256: u64 = 0x12;
+pub const HASH_BLAKE2B_256: u64 = 0xb220;
+const ALLOWED_HASHES: [u64; 2] = [HASH_SHA2_256, HASH_BLAKE2B_256];
+const DIGEST_LENGTH: usize = 32;
+
+const BASE58_ALPHABET: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8; 32] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CidError {
+	// Caractere sau structură invalidă
+	InvalidEncoding,
+	UnsupportedMultibase,
+	UnsupportedVersion,
+	UnsupportedCodec,
+	UnsupportedHash,
+	InvalidDigestLength,
+}
+
+impl CidError {
+	/// Erorile de format, spre deosebire de cele pentru CID-uri valide dar nepermise.
+	pub fn is_malformed(&self) -> bool {
+		matches!(
+			self,
+			CidError::InvalidEncoding |
+				CidError::UnsupportedMultibase |
+				CidError::InvalidDigestLength
+		)
+	}
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Cid {
+	pub version: u8,
+	pub codec: u64,
+	pub hash: u64,
+	pub digest: Vec<u8>,
+}
+
+impl Cid {
+	/// Parsează forma text a unui CID.
+	pub fn parse(input: &[u8]) -> Result<Self, CidError> {
+		// CIDv0: multihash sha2-256 în base58btc, fără prefix multibase
+		if input.len() == 46 && input.starts_with(b"Qm") {
+			let bytes = base58_decode(input)?;
+			return Self::from_bytes(&bytes)
+		}
+
+		let (prefix, rest) = input.split_first().ok_or(CidError::InvalidEncoding)?;
+		let bytes = match prefix {
+			b'b' => base32_decode(rest, false)?,
+			b'B' => base32_decode(rest, true)?,
+			b'z' => base58_decode(rest)?,
+			b'f' => base16_decode(rest, false)?,
+			b'F' => base16_decode(rest, true)?,
+			_ => return Err(CidError::UnsupportedMultibase),
+		};
+		// Un CIDv0 nu poate fi scris cu prefix multibase
+		if bytes.first() == Some(&0x12) {
+			return Err(CidError::UnsupportedVersion)
+		}
+		Self::from_bytes(&bytes)
+	}
+
+	/// Parsează forma binară canonică.
+	pub fn from_bytes(bytes: &[u8]) -> Result<Self, CidError> {
+		if bytes.len() == 2 + DIGEST_LENGTH && bytes[0] == 0x12 && bytes[1] == 0x20 {
+			return Ok(Cid {
+				version: 0,
+				codec: CODEC_DAG_PB,
+				hash: HASH_SHA2_256,
+				digest: bytes[2..].to_vec(),
+			})
+		}
+
+		let mut rest = bytes;
+		let version = read_varint(&mut rest)?;
+		if version != 1 {
+			return Err(CidError::UnsupportedVersion)
+		}
+		let codec = read_varint(&mut rest)?;
+		if !ALLOWED_CODECS.contains(&codec) {
+			return Err(CidError::UnsupportedCodec)
+		}
+		let hash = read_varint(&mut rest)?;
+		if !ALLOWED_HASHES.contains(&hash) {
+			return Err(CidError::UnsupportedHash)
+		}
+		let length = read_varint(&mut rest)?;
+		if length != DIGEST_LENGTH as u64 || rest.len() != DIGEST_LENGTH {
+			return Err(CidError::InvalidDigestLength)
+		}
+
+		Ok(Cid { version: 1, codec, hash, digest: rest.to_vec() })
+	}
+
+	pub fn to_bytes(&self) -> Vec<u8> {
+		let mut bytes = Vec::with_capacity(4 + 2 + self.digest.len());
+		if self.version == 1 {
+			write_varint(&mut bytes, 1);
+			write_varint(&mut bytes, self.codec);
+		}
+		write_varint(&mut bytes, self.hash);
+		write_varint(&mut bytes, self.digest.len() as u64);
+		bytes.extend_from_slice(&self.digest);
+		bytes
+	}
+
+	/// Forma text: base58btc pentru CIDv0, base32 (prefix `b`) pentru CIDv1.
+	pub fn to_string_bytes(&self) -> Vec<u8> {
+		if self.version == 0 {
+			return base58_encode(&self.to_bytes())
+		}
+		let mut text = sp_std::vec![b'b'];
+		text.extend(base32_encode(&self.to_bytes()));
+		text
+	}
+}
+
+/// Forma text a unui CID păstrat în forma binară canonică.
+pub fn render(bytes: &[u8]) -> Option<Vec<u8>> {
+	Cid::from_bytes(bytes).ok().map(|cid| cid.to_string_bytes())
+}
+
+fn read_varint(input: &mut &[u8]) -> Result<u64, CidError> {
+	let mut value: u64 = 0;
+	for (index, byte) in input.iter().enumerate().take(9) {
+		value |= u64::from(byte & 0x7f) << (7 * index);
+		if byte & 0x80 == 0 {
+			// Varint-urile trebuie codificate minimal
+			if index > 0 && *byte == 0 {
+				return Err(CidError::InvalidEncoding)
+			}
+			*input = &input[index + 1..];
+			return Ok(value)
+		}
+	}
+	Err(CidError::InvalidEncoding)
+}
+
+fn write_varint(output: &mut Vec<u8>, mut value: u64) {
+	while value >= 0x80 {
+		output.push((value as u8) | 0x80);
+		value >>= 7;
+	}
+	output.push(value as u8);
+}
+
+fn base58_decode(input: &[u8]) -> Result<Vec<u8>, CidError> {
+	let mut bytes: Vec<u8> = Vec::with_capacity(input.len());
+	for c in input {
+		let mut carry = BASE58_ALPHABET
+			.iter()
+			.position(|a| a == c)
+			.ok_or(CidError::InvalidEncoding)? as u32;
+		for byte in bytes.iter_mut() {
+			carry += u32::from(*byte) * 58;
+			*byte = carry as u8;
+			carry >>= 8;
+		}
+		while carry > 0 {
+			bytes.push(carry as u8);
+			carry >>= 8;
+		}
+	}
+	// Fiecare `1` de la început reprezintă un octet zero
+	let zeros = input.iter().take_while(|c| **c == b'1').count();
+	bytes.extend(sp_std::iter::repeat(0).take(zeros));
+	bytes.reverse();
+	Ok(bytes)
+}
+
+fn base58_encode(input: &[u8]) -> Vec<u8> {
+	let mut digits: Vec<u8> = Vec::with_capacity(input.len() * 138 / 100 + 1);
+	for byte in input {
+		let mut carry = u32::from(*byte);
+		for digit in digits.iter_mut() {
+			carry += u32::from(*digit) << 8;
+			*digit = (carry % 58) as u8;
+			carry /= 58;
+		}
+		while carry > 0 {
+			digits.push((carry % 58) as u8);
+			carry /= 58;
+		}
+	}
+	let zeros = input.iter().take_while(|b| **b == 0).count();
+	let mut text: Vec<u8> = sp_std::iter::repeat(b'1').take(zeros).collect();
+	text.extend(digits.iter().rev().map(|d| BASE58_ALPHABET[*d as usize]));
+	text
+}
+
+fn base32_decode(input: &[u8], upper: bool) -> Result<Vec<u8>, CidError> {
+	let mut bytes = Vec::with_capacity(input.len() * 5 / 8);
+	let mut buffer: u32 = 0;
+	let mut bits = 0;
+	for c in input {
+		// Alfabetul nu poate amesteca litere mari și mici
+		if c.is_ascii_alphabetic() && c.is_ascii_uppercase() != upper {
+			return Err(CidError::InvalidEncoding)
+		}
+		let c = c.to_ascii_lowercase();
+		let value =
+			BASE32_ALPHABET.iter().position(|a| *a == c).ok_or(CidError::InvalidEncoding)? as u32;
+		buffer = (buffer << 5) | value;
+		bits += 5;
+		if bits >= 8 {
+			bits -= 8;
+			bytes.push((buffer >> bits) as u8);
+			buffer &= (1 << bits) - 1;
+		}
+	}
+	// Biții rămași trebuie să fie doar completare
+	if bits >= 5 || buffer != 0 {
+		return Err(CidError::InvalidEncoding)
+	}
+	Ok(bytes)
+}
+
+fn base32_encode(input: &[u8]) -> Vec<u8> {
+	let mut text = Vec::with_capacity((input.len() * 8 + 4) / 5);
+	let mut buffer: u32 = 0;
+	let mut bits = 0;
+	for byte in input {
+		buffer = (buffer << 8) | u32::from(*byte);
+		bits += 8;
+		while bits >= 5 {
+			bits -= 5;
+			text.push(BASE32_ALPHABET[((buffer >> bits) & 0x1f) as usize]);
+		}
+		buffer &= (1 << bits) - 1;
+	}
+	if bits > 0 {
+		text.push(BASE32_ALPHABET[((buffer << (5 - bits)) & 0x1f) as usize]);
+	}
+	text
+}
+
+fn base16_decode(input: &[u8], upper: bool) -> Result<Vec<u8>, CidError> {
+	if input.len() % 2 != 0 {
+		return Err(CidError::InvalidEncoding)
+	}
+	let nibble = |c: u8| -> Result<u8, CidError> {
+		match c {
+			b'0'..=b'9' => Ok(c - b'0'),
+			b'a'..=b'f' if !upper => Ok(c - b'a' + 10),
+			b'A'..=b'F' if upper => Ok(c - b'A' + 10),
+			_ => Err(CidError::InvalidEncoding),
+		}
+	};
+	input.chunks(2).map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?)).collect()
+}
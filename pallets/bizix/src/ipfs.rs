@@ -0,0 +1,75 @@
+//! Cereri către API-ul HTTP al unui nod IPFS (Kubo), folosite de offchain worker.
+//!
+//! Adresa API-ului se citește din stocarea locală offchain, de la cheia [`API_URL_KEY`]
+//! (de exemplu prin RPC-ul `offchain_localStorageSet`). Dacă lipsește, se folosește un nod local.
+
+use sp_runtime::offchain::{http, Duration, StorageKind};
+use sp_std::vec::Vec;
+
+/// Cheia din stocarea locală offchain (persistentă) cu adresa API-ului IPFS, ca text brut.
+pub const API_URL_KEY: &[u8] = b"bizix::ipfs-api";
+/// Adresa folosită când operatorul nu a configurat alta.
+pub const DEFAULT_API_URL: &[u8] = b"http://127.0.0.1:5001";
+
+const TIMEOUT_MS: u64 = 5_000;
+
+pub fn api_url() -> Vec<u8> {
+	sp_io::offchain::local_storage_get(StorageKind::PERSISTENT, API_URL_KEY)
+		.unwrap_or_else(|| DEFAULT_API_URL.to_vec())
+}
+
+/// Dimensiunea blocului rădăcină, dacă nodul IPFS îl poate găsi. Spre deosebire de
+/// `object/stat`, care cere dag-pb, `block/stat` funcționează pentru toate codec-urile permise
+/// de [`crate::cid`].
+pub fn block_stat(api: &[u8], cid: &[u8]) -> Result<u64, http::Error> {
+	let body = post(api, b"/api/v0/block/stat?arg=", cid)?;
+	parse_block_size(&body).ok_or(http::Error::Unknown)
+}
+
+/// Fixează (pin) obiectul pe nodul IPFS.
+pub fn pin_add(api: &[u8], cid: &[u8]) -> Result<(), http::Error> {
+	post(api, b"/api/v0/pin/add?arg=", cid).map(|_| ())
+}
+
+fn post(api: &[u8], path: &[u8], cid: &[u8]) -> Result<Vec<u8>, http::Error> {
+	// CID-urile sunt base58/base32; orice alt caracter ar putea modifica URL-ul
+	if cid.is_empty() || !cid.iter().all(u8::is_ascii_alphanumeric) {
+		return Err(http::Error::Unknown)
+	}
+
+	let mut url = Vec::with_capacity(api.len() + path.len() + cid.len());
+	url.extend_from_slice(api);
+	url.extend_from_slice(path);
+	url.extend_from_slice(cid);
+	let url = sp_std::str::from_utf8(&url).map_err(|_| http::Error::Unknown)?;
+
+	let deadline = sp_io::offchain::timestamp().add(Duration::from_millis(TIMEOUT_MS));
+	let pending = http::Request::post(url, Vec::<&[u8]>::new())
+		.deadline(deadline)
+		.send()
+		.map_err(|_| http::Error::IoError)?;
+	let response = pending.try_wait(deadline).map_err(|_| http::Error::DeadlineReached)??;
+	if response.code != 200 {
+		return Err(http::Error::Unknown)
+	}
+
+	Ok(response.body().collect())
+}
+
+/// Extrage `Size` din răspunsul JSON al `block/stat`.
+pub fn parse_block_size(body: &[u8]) -> Option<u64> {
+	const FIELD: &[u8] = b"\"Size\":";
+	let start = body.windows(FIELD.len()).position(|window| window == FIELD)? + FIELD.len();
+	let digits: Vec<u8> = body[start..]
+		.iter()
+		.skip_while(|c| c.is_ascii_whitespace())
+		.take_while(|c| c.is_ascii_digit())
+		.copied()
+		.collect();
+	if digits.is_empty() {
+		return None
+	}
+	digits
+		.iter()
+		.try_fold(0u64, |acc, c| acc.checked_mul(10)?.checked_add(u64::from(c - b'0')))
+}
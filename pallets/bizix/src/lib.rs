@@ -60,6 +60,54 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Parsarea și ordonarea versiunilor aplicațiilor
+pub mod semver;
+
+// Clientul HTTP pentru nodul IPFS interogat de offchain worker
+pub mod ipfs;
+
+// Parsarea și validarea CID-urilor IPFS
+pub mod cid;
+
+// Migrările stocării între versiuni
+pub mod migrations;
+
+// Tipurile de semnare offchain pentru runtime-urile de test
+#[cfg(feature = "std")]
+pub mod testing;
+
+/// Tipul cheilor folosite de offchain worker pentru a semna atestările IPFS.
+pub const KEY_TYPE: sp_runtime::KeyTypeId = sp_runtime::KeyTypeId(*b"bizx");
+
+/// Criptografia cheilor offchain worker-ului. Cheile se adaugă în keystore-ul nodului cu tipul
+/// `bizx` (de exemplu prin `author_insertKey`).
+pub mod crypto {
+	use super::KEY_TYPE;
+	use sp_core::sr25519::Signature as Sr25519Signature;
+	use sp_runtime::{
+		app_crypto::{app_crypto, sr25519},
+		traits::Verify,
+		MultiSignature, MultiSigner,
+	};
+	app_crypto!(sr25519, KEY_TYPE);
+
+	pub struct AuthorityId;
+
+	impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AuthorityId {
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+
+	impl frame_system::offchain::AppCrypto<<Sr25519Signature as Verify>::Signer, Sr25519Signature>
+		for AuthorityId
+	{
+		type RuntimeAppPublic = Public;
+		type GenericSignature = sp_core::sr25519::Signature;
+		type GenericPublic = sp_core::sr25519::Public;
+	}
+}
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
@@ -68,7 +116,24 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	use sp_std::vec::Vec;
-	use frame_support::traits::{Currency, ReservableCurrency};
+	use frame_support::storage::with_storage_layer;
+	use frame_support::traits::{
+		Currency, Imbalance, OnUnbalanced, ReservableCurrency, SortedMembers,
+	};
+	use frame_system::offchain::{AppCrypto, CreateSignedTransaction, SendSignedTransaction, Signer};
+	use sp_runtime::offchain::storage::StorageValueRef;
+	use sp_runtime::{
+		traits::{One, Saturating, Zero},
+		PerThing, Perbill,
+	};
+	use crate::semver::SemVer;
+	use crate::cid::{self, Cid};
+
+	/// Numărul maxim de elemente întoarse de interogările paginate.
+	pub const MAX_PAGE_SIZE: u32 = 100;
+
+	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
 
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
@@ -79,46 +144,100 @@ pub mod pallet {
 	/// These types are defined generically and made concrete when the pallet is declared in the
 	/// `runtime/src/lib.rs` file of your chain.
 	#[pallet::config]
-    pub trait Config: frame_system::Config {
+    pub trait Config: frame_system::Config + CreateSignedTransaction<Call<Self>> {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type WeightInfo: WeightInfo;
  
        // Tipuri de date pentru bizix-core
-	   type IPFSAddress: Parameter + Member + Default + Clone;
-	   type ApplicationName: Parameter + Member + Default + Clone;
-	   type ApplicationVersion: Parameter + Member + Default + Clone;
-	   type ProposalPrice: Parameter + Member + Default + Copy;
-	   type ProxmoxTemplateID: Parameter + Member + Default + Clone;
-	   //type TechnicalCouncilOrigin: EnsureOrigin<Self::RuntimeOrigin>;
-	  
+	   // Adresa IPFS (CID) în forma binară canonică (vezi `cid`)
+	   type IPFSAddress: Parameter
+		   + Member
+		   + Default
+		   + Clone
+		   + MaxEncodedLen
+		   + AsRef<[u8]>
+		   + TryFrom<Vec<u8>>;
+	   type ApplicationName: Parameter + Member + Default + Clone + MaxEncodedLen;
+	   // Versiunea trebuie să fie SemVer valid (vezi `semver`)
+	   type ApplicationVersion: Parameter + Member + Default + Clone + MaxEncodedLen + AsRef<[u8]>;
+	   type ProxmoxTemplateID: Parameter + Member + Default + Clone + MaxEncodedLen;
+	   // Originea deciziilor comitetului tehnic (aprobare, respingere, închiderea votului)
 	   type TechnicalCommittee: EnsureOrigin<Self::RuntimeOrigin>;
 	   type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
+
+	   // Depozitul rezervat la trimiterea unei propuneri
+	   #[pallet::constant]
+	   type ProposalPrice: Get<BalanceOf<Self>>;
+	   // Procentul din depozit care se pierde la respingerea propunerii
+	   #[pallet::constant]
+	   type RejectionSlash: Get<Perbill>;
+	   // Destinatia fondurilor taiate (de exemplu trezoreria)
+	   type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+	   // Membrii comitetului tehnic, singurii care pot vota propunerile
+	   type CommitteeMembers: SortedMembers<Self::AccountId>;
+	   // Pragul de aprobare: procent din mărimea comitetului sau număr absolut de voturi
+	   #[pallet::constant]
+	   type ApprovalThreshold: Get<VoteThreshold>;
+	   // Durata votului, în blocuri, de la trecerea propunerii în discuție
+	   #[pallet::constant]
+	   type VotingPeriod: Get<BlockNumberFor<Self>>;
+	   // Numărul maxim de voturi închise automat într-un singur bloc
+	   #[pallet::constant]
+	   type MaxAutoClosuresPerBlock: Get<u32>;
+	   // Numărul maxim de voturi înregistrate pentru o propunere (cel puțin mărimea comitetului)
+	   #[pallet::constant]
+	   type MaxVoters: Get<u32>;
+	   // Numărul maxim de versiuni păstrate în catalog pentru o aplicație
+	   #[pallet::constant]
+	   type MaxReleases: Get<u32>;
+	   // Numărul minim de recenzii necesar pentru închiderea votului; tot atâția recenzenți sunt
+	   // desemnați automat la începerea votului. Pentru un comitet mic se cer cel mult câte
+	   // recenzii pot da membrii în afară de propunător
+	   #[pallet::constant]
+	   type MinReviews: Get<u32>;
+	   // Numărul maxim de auditori înregistrați, deci și de atestări de audit ale unei propuneri
+	   #[pallet::constant]
+	   type MaxAuditors: Get<u32>;
+
+	   // Cheia cu care offchain worker-ul semnează atestările IPFS
+	   type AuthorityId: AppCrypto<Self::Public, Self::Signature>;
+	   // Conturile care pot raporta disponibilitatea conținutului IPFS
+	   type Attesters: SortedMembers<Self::AccountId>;
    }
 
-   #[pallet::pallet]
-   #[pallet::without_storage_info]
-   pub struct Pallet<T>(_);
+   /// Câte propuneri verifică offchain worker-ul într-un bloc.
+   pub const MAX_CONTENT_CHECKS_PER_BLOCK: usize = 5;
+   /// După câte blocuri se reîncearcă verificarea unei propuneri.
+   pub const CONTENT_CHECK_RETRY_BLOCKS: u32 = 10;
+   /// În câte blocuri consecutive se caută loc pentru închiderea automată a unui vot.
+   pub const MAX_CLOSURE_SCHEDULE_BLOCKS: u32 = 100;
+   /// De câte ori se poate prelungi un vot fără recenzii sau audit înainte de a fi respins.
+   pub const MAX_VOTING_EXTENSIONS: u32 = 3;
 
-   // Stocare individuală cu StorageValue pentru fiecare câmp al propunerii
-   #[pallet::storage]
-   #[pallet::getter(fn proposal_ipfs_address)]
-   pub type ProposalIPFSAddress<T: Config> = StorageValue<_, T::IPFSAddress>;
+   /// Numărul maxim de schimbări de status păstrate în istoricul unei propuneri. Fără cicluri, o
+   /// propunere are cel mult 7 intrări, de la `New` până la `Revoked`. Restabilirea din
+   /// `Superseded`, după revocarea versiunii care a înlocuit-o, formează un ciclu de cel mult
+   /// `STATUS_CYCLE_LENGTH` intrări și se face doar dacă istoricul are loc pentru tot ciclul, deci
+   /// limita nu este depășită, iar revocarea reușește întotdeauna. 32 de intrări permit 5 cicluri.
+   pub type MaxStatusChanges = ConstU32<32>;
 
-   #[pallet::storage]
-   #[pallet::getter(fn proposal_name)]
-   pub type ProposalName<T: Config> = StorageValue<_, T::ApplicationName>;
+   /// Intrările unui ciclu de restabilire: statusul restabilit, `Deployed`, `Deprecated`,
+   /// `Superseded` și, la final, `Revoked`.
+   pub const STATUS_CYCLE_LENGTH: u32 = 5;
 
-   #[pallet::storage]
-   #[pallet::getter(fn proposal_version)]
-   pub type ProposalVersion<T: Config> = StorageValue<_, T::ApplicationVersion>;
+   /// Numărul maxim de modificări ale unei propuneri înainte de vot.
+   pub type MaxAmendments = ConstU32<8>;
 
-   #[pallet::storage]
-   #[pallet::getter(fn proposal_template_id)]
-   pub type ProposalTemplateID<T: Config> = StorageValue<_, T::ProxmoxTemplateID>;
+   /// Nota maximă dintr-o recenzie.
+   pub const MAX_REVIEW_SCORE: u8 = 10;
 
-   #[pallet::storage]
-   #[pallet::getter(fn proposal_status)]
-   pub type ProposalStatus<T: Config> = StorageValue<_, ProposalStatusEnum>;
+   /// Versiunea stocării; vezi `migrations` pentru trecerile între versiuni.
+   const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+   #[pallet::pallet]
+   #[pallet::storage_version(STORAGE_VERSION)]
+   pub struct Pallet<T>(_);
 
    #[pallet::storage]
    #[pallet::getter(fn proposal_count)]
@@ -128,16 +247,173 @@ pub mod pallet {
    #[pallet::getter(fn proposals)]
    pub type Proposals<T: Config> = StorageMap<_, Blake2_128Concat, u32, Proposal<T>>;
 
+   // Indexuri secundare pentru interogările paginate din runtime API
+   #[pallet::storage]
+   pub type ProposalsByStatus<T: Config> =
+	   StorageDoubleMap<_, Twox64Concat, ProposalStatusEnum, Twox64Concat, u32, ()>;
+
+   #[pallet::storage]
+   pub type ProposalsByProposer<T: Config> =
+	   StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u32, ()>;
+
    #[pallet::storage]
    #[pallet::getter(fn current_proposal_id)]
    pub type CurrentProposalId<T: Config> = StorageValue<_, u32, ValueQuery>;
 
    #[pallet::storage]
    #[pallet::getter(fn votes)]
-   pub type Votes<T: Config> = StorageMap<_, Blake2_128Concat, u32, Vec<T::AccountId>>;
+   pub type Votes<T: Config> = StorageMap<_, Blake2_128Concat, u32, VoteRecord<T>>;
+
+   // Propunerile al căror vot expiră la un anumit bloc, cel mult `MaxAutoClosuresPerBlock`
+   #[pallet::storage]
+   #[pallet::getter(fn voting_deadlines)]
+   pub type VotingDeadlines<T: Config> =
+	   StorageMap<
+		   _,
+		   Twox64Concat,
+		   BlockNumberFor<T>,
+		   BoundedVec<u32, T::MaxAutoClosuresPerBlock>,
+		   ValueQuery,
+	   >;
+
+   // Istoricul schimbărilor de status, fiecare marcat cu blocul la care a avut loc
+   #[pallet::storage]
+   #[pallet::getter(fn status_history)]
+   pub type StatusHistory<T: Config> =
+	   StorageMap<
+		   _,
+		   Blake2_128Concat,
+		   u32,
+		   BoundedVec<(ProposalStatusEnum, BlockNumberFor<T>), MaxStatusChanges>,
+		   ValueQuery,
+	   >;
+
+   // Modificările făcute de propunător cât timp propunerea era nouă
+   #[pallet::storage]
+   #[pallet::getter(fn amendments)]
+   pub type Amendments<T: Config> =
+	   StorageMap<_, Blake2_128Concat, u32, BoundedVec<Amendment<T>, MaxAmendments>, ValueQuery>;
+
+   // Recenzenții desemnați pentru o propunere; sunt membri ai comitetului, deci limita este
+   // aceeași ca pentru voturi
+   #[pallet::storage]
+   #[pallet::getter(fn reviewers)]
+   pub type Reviewers<T: Config> =
+	   StorageMap<_, Blake2_128Concat, u32, BoundedVec<T::AccountId, T::MaxVoters>, ValueQuery>;
+
+   #[pallet::storage]
+   #[pallet::getter(fn reviews)]
+   pub type Reviews<T: Config> =
+	   StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, Review<T>>;
+
+   // Poziția din comitet de la care continuă desemnarea automată a recenzenților
+   #[pallet::storage]
+   pub type NextReviewer<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+   // De câte ori a fost prelungit votul unei propuneri
+   #[pallet::storage]
+   #[pallet::getter(fn voting_extensions)]
+   pub type VotingExtensions<T: Config> = StorageMap<_, Twox64Concat, u32, u32, ValueQuery>;
+
+   // Auditorii de securitate înregistrați de comitet, cu blocul înregistrării
+   #[pallet::storage]
+   #[pallet::getter(fn auditors)]
+   pub type Auditors<T: Config> =
+	   CountedStorageMap<_, Blake2_128Concat, T::AccountId, BlockNumberFor<T>>;
+
+   // Atestările de audit pentru versiunea unei aplicații, câte una de la fiecare auditor; cel
+   // mult `MaxAuditors` pentru o propunere
+   #[pallet::storage]
+   pub type Audits<T: Config> =
+	   StorageDoubleMap<_, Blake2_128Concat, u32, Blake2_128Concat, T::AccountId, AuditAttestation<T>>;
+
+   // Dacă aprobarea unei propuneri necesită o atestare de audit valabilă
+   #[pallet::storage]
+   #[pallet::getter(fn audit_required)]
+   pub type AuditRequired<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+   // Catalogul aplicațiilor aprobate, indexat după numele aplicației
+   #[pallet::storage]
+   #[pallet::getter(fn applications)]
+   pub type Applications<T: Config> =
+	   StorageMap<_, Blake2_128Concat, T::ApplicationName, Application<T>>;
+
+   // Numărul aplicațiilor din catalog, ca statisticile să nu parcurgă `Applications`
+   #[pallet::storage]
+   #[pallet::getter(fn application_count)]
+   pub type ApplicationCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+   // Ultima atestare a conținutului IPFS al unei propuneri
+   #[pallet::storage]
+   #[pallet::getter(fn content_checks)]
+   pub type ContentChecks<T: Config> = StorageMap<_, Blake2_128Concat, u32, ContentCheck<T>>;
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct ContentCheck<T: Config> {
+	   pub available: bool,
+	   // Dimensiunea blocului rădăcină raportată de nodul IPFS, în octeți
+	   pub size: Option<u64>,
+	   pub pinned: bool,
+	   pub checked_at: BlockNumberFor<T>,
+	   pub attester: T::AccountId,
+   }
+
+   #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   pub enum ReviewVerdict {
+	   Approve,
+	   Reject,
+	   // Propunerea poate fi aprobată după corecturi
+	   RequestChanges,
+   }
 
-   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+   // Recenzia unui membru al comitetului; raportul complet este publicat pe IPFS
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct Review<T: Config> {
+	   pub verdict: ReviewVerdict,
+	   // Între 0 și `MAX_REVIEW_SCORE`
+	   pub score: u8,
+	   pub report: T::IPFSAddress,
+	   pub submitted_at: BlockNumberFor<T>,
+   }
+
+   // Numărul de probleme găsite la audit, pe niveluri de gravitate
+   #[derive(Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   pub struct AuditSeverities {
+	   pub critical: u32,
+	   pub high: u32,
+	   pub medium: u32,
+	   pub low: u32,
+   }
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct AuditAttestation<T: Config> {
+	   // CID-ul raportului de audit
+	   pub report: T::IPFSAddress,
+	   pub severities: AuditSeverities,
+	   pub attested_at: BlockNumberFor<T>,
+	   // Atestarea nu mai este valabilă începând cu acest bloc
+	   pub expires_at: BlockNumberFor<T>,
+   }
+
+   // Valorile înlocuite la o modificare a propunerii
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct Amendment<T: Config> {
+	   pub ipfs_address: T::IPFSAddress,
+	   pub template_id: T::ProxmoxTemplateID,
+	   pub amended_at: BlockNumberFor<T>,
+   }
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
 	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
     pub struct Proposal<T: Config> {
         pub proposer: T::AccountId,
         pub ipfs_address: T::IPFSAddress,
@@ -145,8 +421,63 @@ pub mod pallet {
         pub version: T::ApplicationVersion,
         pub template_id: T::ProxmoxTemplateID,
         pub status: ProposalStatusEnum,
+        pub deposit: BalanceOf<T>,
+        // Blocul la care se închide votul, setat la trecerea în discuție
+        pub voting_end: Option<BlockNumberFor<T>>,
     }
 
+   // O versiune aprobată a unei aplicații
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct ApplicationRelease<T: Config> {
+	   pub proposal_id: u32,
+	   pub version: T::ApplicationVersion,
+	   pub ipfs_address: T::IPFSAddress,
+	   pub template_id: T::ProxmoxTemplateID,
+	   pub approved_at: BlockNumberFor<T>,
+   }
+
+   // Intrarea din catalog a unei aplicații
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct Application<T: Config> {
+	   // Propunătorul primei versiuni aprobate
+	   pub publisher: T::AccountId,
+	   // Versiunile aprobate, ordonate crescător după versiune; cele mai vechi sunt eliminate
+	   // când se atinge `MaxReleases`
+	   pub releases: BoundedVec<ApplicationRelease<T>, T::MaxReleases>,
+	   // Indexul ultimei versiuni din `releases`
+	   pub latest: u32,
+   }
+
+   impl<T: Config> Application<T> {
+	   pub fn latest_release(&self) -> Option<&ApplicationRelease<T>> {
+		   self.releases.get(self.latest as usize)
+	   }
+   }
+
+   // Evidența voturilor pentru o propunere aflată în discuție
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct VoteRecord<T: Config> {
+	   pub ayes: u32,
+	   pub nays: u32,
+	   pub voters: BoundedVec<(T::AccountId, bool), T::MaxVoters>,
+	   // Numărul minim de voturi "pentru" necesar aprobării, fixat la deschiderea votului
+	   pub threshold: u32,
+   }
+
+   #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   pub enum VoteThreshold {
+	   // Procent din numărul membrilor comitetului (rotunjit în sus)
+	   Proportion(Perbill),
+	   // Număr fix de voturi "pentru"
+	   Absolute(u32),
+   }
+
    // Evenimente
   #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -175,6 +506,119 @@ pub mod pallet {
             old_status: ProposalStatusEnum,
             new_status: ProposalStatusEnum,
         },
+        ProposalDepositReserved {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ProposalDepositReleased {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ProposalDepositSlashed {
+            proposal_id: u32,
+            who: T::AccountId,
+            slashed: BalanceOf<T>,
+            returned: BalanceOf<T>,
+        },
+        ProposalDepositRefunded {
+            proposal_id: u32,
+            who: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        ProposalWithdrawn {
+            proposal_id: u32,
+            who: T::AccountId,
+        },
+        ProposalAmended {
+            proposal_id: u32,
+            who: T::AccountId,
+            ipfs_address: T::IPFSAddress,
+            template_id: T::ProxmoxTemplateID,
+        },
+        ReviewerAssigned {
+            proposal_id: u32,
+            reviewer: T::AccountId,
+        },
+        ReviewSubmitted {
+            proposal_id: u32,
+            reviewer: T::AccountId,
+            verdict: ReviewVerdict,
+            score: u8,
+        },
+        AuditorRegistered {
+            who: T::AccountId,
+        },
+        AuditorRemoved {
+            who: T::AccountId,
+        },
+        AuditAttested {
+            proposal_id: u32,
+            auditor: T::AccountId,
+            severities: AuditSeverities,
+            expires_at: BlockNumberFor<T>,
+        },
+        AuditRequirementChanged {
+            required: bool,
+        },
+        // Votul nu a putut fi închis la termen din lipsa recenziilor sau a auditului
+        VotingExtended {
+            proposal_id: u32,
+            voting_end: BlockNumberFor<T>,
+        },
+        // Votul nu s-a putut închide nici după `MAX_VOTING_EXTENSIONS` prelungiri; propunerea
+        // este respinsă, iar depozitul returnat integral
+        VotingExpired {
+            proposal_id: u32,
+        },
+        // Închiderea automată a eșuat și a fost anulată; propunerea rămâne în discuție, iar
+        // comitetul poate închide votul manual
+        VotingClosureFailed {
+            proposal_id: u32,
+            error: DispatchError,
+        },
+        VotingStarted {
+            proposal_id: u32,
+            voting_end: BlockNumberFor<T>,
+        },
+        VoteCast {
+            proposal_id: u32,
+            who: T::AccountId,
+            approve: bool,
+        },
+        VotingClosed {
+            proposal_id: u32,
+            ayes: u32,
+            nays: u32,
+            threshold: u32,
+            approved: bool,
+        },
+        ApplicationReleased {
+            name: T::ApplicationName,
+            version: T::ApplicationVersion,
+            proposal_id: u32,
+        },
+        ApplicationReleaseRemoved {
+            name: T::ApplicationName,
+            proposal_id: u32,
+        },
+        ContentChecked {
+            proposal_id: u32,
+            available: bool,
+            size: Option<u64>,
+            pinned: bool,
+            attester: T::AccountId,
+        },
+        // Conținutul nu a putut fi găsit; propunerea nu poate intra la vot
+        ContentUnavailable {
+            proposal_id: u32,
+        },
+        // O propunere reținută la migrarea v1 a fost readusă
+        HeldProposalRecovered {
+            proposal_id: u32,
+            status: ProposalStatusEnum,
+        },
     }
 
    // Erori
@@ -184,15 +628,80 @@ pub mod pallet {
 	   AlreadyVoted,
 	   InvalidProposalStatus,
 	   InsufficientFunds,
+	   NotProposer,
+	   NotCommitteeMember,
+	   ProposerCannotVote,
+	   VotingNotOpen,
+	   // Nu mai există loc pentru închiderea automată a votului în blocurile următoare
+	   VotingQueueFull,
+	   // Tranziții de status nepermise, câte o eroare pentru fiecare status țintă
+	   CannotReturnToNew,
+	   CannotStartDiscussion,
+	   CannotApprove,
+	   CannotReject,
+	   CannotWithdraw,
+	   CannotDeploy,
+	   CannotDeprecate,
+	   CannotSupersede,
+	   CannotRevoke,
+	   // Versiunea nu respectă SemVer
+	   InvalidVersion,
+	   // Versiunea nu este mai mare decât ultima versiune aprobată a aplicației
+	   VersionNotHigher,
+	   NotAttester,
+	   // CID-ul nu poate fi decodat
+	   InvalidCid,
+	   // CID valid, dar cu multicodec sau multihash care nu este permis
+	   UnsupportedCid,
+	   // Conținutul IPFS al propunerii a fost raportat ca indisponibil
+	   ContentUnavailable,
+	   TooManyVoters,
+	   TooManyStatusChanges,
+	   // Apelul nu mai este suportat
+	   Deprecated,
+	   // Propunerea poate fi modificată doar cât timp este nouă
+	   CannotAmend,
+	   // Modificarea nu schimbă nimic
+	   EmptyAmendment,
+	   TooManyAmendments,
+	   NotReviewer,
+	   AlreadyReviewer,
+	   AlreadyReviewed,
+	   TooManyReviewers,
+	   // Nota depășește `MAX_REVIEW_SCORE`
+	   InvalidScore,
+	   // Votul nu poate fi închis înainte de `MinReviews` recenzii
+	   NotEnoughReviews,
+	   NotAuditor,
+	   AlreadyAuditor,
+	   TooManyAuditors,
+	   // Termenul de valabilitate al atestării a trecut deja
+	   InvalidExpiry,
+	   // Auditul este obligatoriu și propunerea nu are o atestare valabilă
+	   MissingAudit,
+	   // Propunerea a fost respinsă, retrasă sau revocată
+	   CannotAudit,
+	   // Propunerea nu este printre cele reținute la migrarea v1
+	   NotHeld,
    }
 
-   // Enumerare pentru statusul unei propuneri
-   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+   // Enumerare pentru statusul unei propuneri, de la trimitere până la retragerea aplicației
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
    pub enum ProposalStatusEnum {
 	   New,
 	   InDiscussion,
 	   Approved,
 	   Rejected,
+	   // Retrasă de propunător înainte de vot
+	   Withdrawn,
+	   // Cel puțin o instanță a aplicației rulează
+	   Deployed,
+	   // Încă disponibilă, dar nerecomandată pentru instalări noi
+	   Deprecated,
+	   // Înlocuită de o versiune mai nouă a aceleiași aplicații
+	   Superseded,
+	   // Retrasă de comitet (de exemplu din motive de securitate)
+	   Revoked,
    }
 
    // Funcții apelabile
@@ -202,13 +711,27 @@ pub mod pallet {
 	   #[pallet::weight(10_000)]
 	   pub fn submit_proposal(
 		   origin: OriginFor<T>,
-		   ipfs_address: T::IPFSAddress,
+		   // CID-ul în formă text (CIDv0 sau CIDv1 cu prefix multibase)
+		   ipfs_address: Vec<u8>,
 		   name: T::ApplicationName,
 		   version: T::ApplicationVersion,
 		   template_id: T::ProxmoxTemplateID,
 	   ) -> DispatchResult {
 		   let sender = ensure_signed(origin)?;
 
+		   let ipfs_address = Self::parse_cid(&ipfs_address)?;
+		   let parsed_version = SemVer::parse(version.as_ref()).ok_or(Error::<T>::InvalidVersion)?;
+		   if let Some(latest) = Applications::<T>::get(&name)
+			   .as_ref()
+			   .and_then(|application| application.latest_release())
+			   .and_then(|release| SemVer::parse(release.version.as_ref()))
+		   {
+			   ensure!(parsed_version > latest, Error::<T>::VersionNotHigher);
+		   }
+
+		   let deposit = T::ProposalPrice::get();
+		   T::Currency::reserve(&sender, deposit).map_err(|_| Error::<T>::InsufficientFunds)?;
+
 		   let proposal_id = CurrentProposalId::<T>::get();
 		   CurrentProposalId::<T>::put(proposal_id + 1);
 
@@ -221,26 +744,29 @@ pub mod pallet {
 			version: version.clone(),
 			template_id: template_id.clone(),
 			status: ProposalStatusEnum::New,
+			deposit,
+			voting_end: None,
 		};
 
-		   // Stochează individual fiecare câmp al propunerii
-		   //ProposalIPFSAddress::<T>::put(ipfs_address.clone());
-		   //ProposalName::<T>::put(name.clone());
-		   //ProposalVersion::<T>::put(version.clone());
-		   //ProposalTemplateID::<T>::put(template_id.clone());
-		   //ProposalStatus::<T>::put(ProposalStatusEnum::New);
-
 		   ProposalCount::<T>::put(proposal_count + 1);
 		   
 		   Proposals::<T>::insert(proposal_id, proposal);
+		   ProposalsByStatus::<T>::insert(ProposalStatusEnum::New, proposal_id, ());
+		   ProposalsByProposer::<T>::insert(&sender, proposal_id, ());
+		   StatusHistory::<T>::try_append(
+			   proposal_id,
+			   (ProposalStatusEnum::New, <frame_system::Pallet<T>>::block_number()),
+		   )
+		   .map_err(|_| Error::<T>::TooManyStatusChanges)?;
 		   Self::deposit_event(Event::ProposalSubmitted {
 			proposal_id,
-			who: sender,
+			who: sender.clone(),
 			ipfs_address,
 			name,
 			version,
 			template_id,
 			});
+		   Self::deposit_event(Event::ProposalDepositReserved { proposal_id, who: sender, amount: deposit });
 
 		   Ok(())
 	   }
@@ -251,21 +777,12 @@ pub mod pallet {
 		origin: OriginFor<T>,
 		proposal_id: u32
 	) -> DispatchResult {
+		T::TechnicalCommittee::ensure_origin(origin)?;
+
 		Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> Result<(), DispatchError> {
 			let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
-			proposal.status = ProposalStatusEnum::Approved;
-			
-			// Emite evenimentul cu toate detaliile propunerii
-			Self::deposit_event(Event::ProposalApproved {
-				proposal_id,
-				who: proposal.proposer.clone(),
-				ipfs_address: proposal.ipfs_address.clone(),
-				name: proposal.name.clone(),
-				version: proposal.version.clone(),
-				template_id: proposal.template_id.clone(),
-			});
-			
-			Ok(())
+			Self::ensure_can_close(proposal_id, proposal)?;
+			Self::do_approve(proposal_id, proposal)
 		})?;
 	
 		Ok(())
@@ -277,39 +794,30 @@ pub mod pallet {
 		   origin: OriginFor<T>,
 		   proposal_id: u32
 	   ) -> DispatchResult {
-		   // Verifică dacă apelantul este membru al consiliului tehnic
-		   //T::TechnicalCouncilOrigin::ensure_origin(origin)?;
+		   // Verifică dacă apelul vine de la comitetul tehnic
+		   T::TechnicalCommittee::ensure_origin(origin)?;
    
 		   // Obține propunerea și actualizează starea
 		   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> Result<(), DispatchError> {
 			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
-			   proposal.status = ProposalStatusEnum::Rejected;
-			   Ok(())
+			   Self::ensure_reviewed(proposal_id, &proposal.proposer)?;
+			   Self::do_reject(proposal_id, proposal)
 		   })?;
    
-		   Self::deposit_event(Event::ProposalRejected { proposal_id });
-   
 		   Ok(())
 	   }
 
+	   /// Învechit: o tranzacție nu poate întoarce date. Folosiți metoda
+	   /// `BizixApi::proposals_by_status` din runtime API. Apelul va fi eliminat, iar
+	   /// `call_index(3)` rămâne rezervat și după aceea.
 	   #[pallet::call_index(3)]
 	   #[pallet::weight(10_000)]
 	   pub fn get_proposals_by_status(
 		   origin: OriginFor<T>,
-		   status: ProposalStatusEnum,
+		   _status: ProposalStatusEnum,
 	   ) -> DispatchResult {
-		   // Verifică dacă apelul provine de la un utilizator autorizat
-		   let _ = ensure_signed(origin)?;
-   
-		   // Colectează toate propunerile cu statusul specificat
-		   let proposals: Vec<(u32, Proposal<T>)> = Proposals::<T>::iter()
-			   .filter(|(_, proposal)| proposal.status == status)
-			   .collect();
-   
-		   // Emite un eveniment sau returnează rezultatele printr-o cale adecvată
-		   // (de exemplu, printr-un RPC personalizat)
-   
-		   Ok(())
+		   ensure_signed(origin)?;
+		   Err(Error::<T>::Deprecated.into())
 	   }
 
 	   #[pallet::call_index(4)]
@@ -320,24 +828,42 @@ pub mod pallet {
 		   approve: bool
 	   ) -> DispatchResult {
 		   let sender = ensure_signed(origin)?;
+		   ensure!(T::CommitteeMembers::contains(&sender), Error::<T>::NotCommitteeMember);
 
-		   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
-			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
-			   ensure!(proposal.status == ProposalStatusEnum::InDiscussion, Error::<T>::InvalidProposalStatus);
+		   let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+		   ensure!(proposal.status == ProposalStatusEnum::InDiscussion, Error::<T>::InvalidProposalStatus);
+		   ensure!(proposal.proposer != sender, Error::<T>::ProposerCannotVote);
 
-			   Votes::<T>::try_mutate(proposal_id, |voters| -> DispatchResult {
-				   let voters = voters.get_or_insert_with(Vec::new);
-				   ensure!(!voters.contains(&sender), Error::<T>::AlreadyVoted);
+		   Votes::<T>::try_mutate(proposal_id, |maybe_record| -> DispatchResult {
+			   let record = maybe_record.as_mut().ok_or(Error::<T>::VotingNotOpen)?;
 
-				   if approve {
-					   voters.push(sender.clone());
-				   }
-				   Ok(())
-			   })?;
+			   // Un membru își poate schimba votul până la închiderea votării
+			   match record.voters.iter_mut().find(|(voter, _)| voter == &sender) {
+				   Some((_, previous)) => {
+					   ensure!(*previous != approve, Error::<T>::AlreadyVoted);
+					   *previous = approve;
+					   if approve {
+						   record.nays = record.nays.saturating_sub(1);
+					   } else {
+						   record.ayes = record.ayes.saturating_sub(1);
+					   }
+				   },
+				   None => record
+					   .voters
+					   .try_push((sender.clone(), approve))
+					   .map_err(|_| Error::<T>::TooManyVoters)?,
+			   }
 
+			   if approve {
+				   record.ayes = record.ayes.saturating_add(1);
+			   } else {
+				   record.nays = record.nays.saturating_add(1);
+			   }
 			   Ok(())
 		   })?;
 
+		   Self::deposit_event(Event::VoteCast { proposal_id, who: sender, approve });
+
 		   Ok(())
 	   }
 
@@ -352,28 +878,14 @@ pub mod pallet {
 		   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
 			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
 			   ensure!(proposal.status == ProposalStatusEnum::InDiscussion, Error::<T>::InvalidProposalStatus);
+			   Self::ensure_can_close(proposal_id, proposal)?;
 
-			   let voters = Votes::<T>::get(proposal_id).unwrap_or_default();
-			   let total_votes = voters.len() as u32;
-			   
-			   // Definim pragul de aprobare (de exemplu 50%)
-			   let approval_threshold = 1; // Temporar setat la 1 pentru testare
-
-			   if total_votes >= approval_threshold {
-				   proposal.status = ProposalStatusEnum::Approved;
-				   Self::deposit_event(Event::ProposalApproved { 
-                    proposal_id,
-                    who: proposal.proposer.clone(),
-                    ipfs_address: proposal.ipfs_address.clone(),
-                    name: proposal.name.clone(),
-                    version: proposal.version.clone(),
-                    template_id: proposal.template_id.clone()
-                });
-			   } else {
-				   proposal.status = ProposalStatusEnum::Rejected;
-				   Self::deposit_event(Event::ProposalRejected { proposal_id });
-			   }
+			   Self::do_close_voting(proposal_id, proposal)?;
 
+			   // Votul a fost închis manual, nu mai trebuie închis automat
+			   if let Some(voting_end) = proposal.voting_end {
+				   VotingDeadlines::<T>::mutate(voting_end, |ids| ids.retain(|id| *id != proposal_id));
+			   }
 			   Ok(())
 		   })?;
 
@@ -390,32 +902,1016 @@ pub mod pallet {
 		   // Verifică dacă apelantul face parte din comitetul tehnic
 		   T::TechnicalCommittee::ensure_origin(origin)?;
 
-		   // Salvăm statusul vechi înainte de modificare
-		   let old_status = Proposals::<T>::get(proposal_id)
-			   .ok_or(Error::<T>::ProposalNotFound)?
-			   .status;
-
 		   // Actualizează statusul propunerii
 		   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
 			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
-			   
-			   // Opțional: poți adăuga validări suplimentare pentru tranziții permise
-			   match (proposal.status.clone(), new_status.clone()) {
-				   (ProposalStatusEnum::New, ProposalStatusEnum::InDiscussion) => {
-					   proposal.status = new_status.clone();
+
+			   // Aprobarea/respingerea trec prin vot (depozitul trebuie decontat), iar retragerea
+			   // aparține propunătorului
+			   match new_status {
+				   ProposalStatusEnum::InDiscussion => {
+					   let unavailable = ContentChecks::<T>::get(proposal_id).map_or(false, |check| !check.available);
+					   ensure!(!unavailable, Error::<T>::ContentUnavailable);
+					   Self::set_status(proposal_id, proposal, new_status)?;
+					   Self::start_voting(proposal_id, proposal)
+				   },
+				   ProposalStatusEnum::Revoked => {
+					   Self::set_status(proposal_id, proposal, new_status)?;
+					   Self::remove_release(proposal_id, &proposal.name);
 					   Ok(())
 				   },
+				   ProposalStatusEnum::Deployed |
+				   ProposalStatusEnum::Deprecated |
+				   ProposalStatusEnum::Superseded => Self::set_status(proposal_id, proposal, new_status),
 				   _ => Err(Error::<T>::InvalidProposalStatus.into())
 			   }
+		   })
+	   }
+
+	   #[pallet::call_index(7)]
+	   #[pallet::weight(10_000)]
+	   pub fn withdraw_proposal(
+		   origin: OriginFor<T>,
+		   proposal_id: u32
+	   ) -> DispatchResult {
+		   let sender = ensure_signed(origin)?;
+
+		   let deposit = Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> Result<BalanceOf<T>, DispatchError> {
+			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+			   ensure!(proposal.proposer == sender, Error::<T>::NotProposer);
+			   Self::set_status(proposal_id, proposal, ProposalStatusEnum::Withdrawn)?;
+			   Ok(proposal.deposit)
+		   })?;
+
+		   // Depozitul se returnează integral la retragere
+		   let remaining = T::Currency::unreserve(&sender, deposit);
+
+		   Self::deposit_event(Event::ProposalWithdrawn { proposal_id, who: sender.clone() });
+		   Self::deposit_event(Event::ProposalDepositRefunded {
+			   proposal_id,
+			   who: sender,
+			   amount: deposit.saturating_sub(remaining),
+		   });
+
+		   Ok(())
+	   }
+
+	   /// Atestarea trimisă de offchain worker după interogarea nodului IPFS.
+	   #[pallet::call_index(8)]
+	   #[pallet::weight(10_000)]
+	   pub fn submit_content_check(
+		   origin: OriginFor<T>,
+		   proposal_id: u32,
+		   available: bool,
+		   size: Option<u64>,
+		   pinned: bool,
+	   ) -> DispatchResult {
+		   let attester = ensure_signed(origin)?;
+		   ensure!(T::Attesters::contains(&attester), Error::<T>::NotAttester);
+		   ensure!(Proposals::<T>::contains_key(proposal_id), Error::<T>::ProposalNotFound);
+
+		   ContentChecks::<T>::insert(proposal_id, ContentCheck {
+			   available,
+			   size,
+			   pinned,
+			   checked_at: <frame_system::Pallet<T>>::block_number(),
+			   attester: attester.clone(),
+		   });
+
+		   if !available {
+			   Self::deposit_event(Event::ContentUnavailable { proposal_id });
+		   }
+		   Self::deposit_event(Event::ContentChecked { proposal_id, available, size, pinned, attester });
+		   Ok(())
+	   }
+
+	   /// Propunătorul poate corecta adresa IPFS sau șablonul cât timp propunerea este nouă.
+	   /// Valorile vechi se păstrează în `Amendments`.
+	   #[pallet::call_index(9)]
+	   #[pallet::weight(10_000)]
+	   pub fn amend_proposal(
+		   origin: OriginFor<T>,
+		   proposal_id: u32,
+		   // CID-ul în formă text, ca la `submit_proposal`
+		   ipfs_address: Option<Vec<u8>>,
+		   template_id: Option<T::ProxmoxTemplateID>,
+	   ) -> DispatchResult {
+		   let sender = ensure_signed(origin)?;
+		   let ipfs_address = ipfs_address.map(|cid| Self::parse_cid(&cid)).transpose()?;
+
+		   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
+			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+			   ensure!(proposal.proposer == sender, Error::<T>::NotProposer);
+			   ensure!(proposal.status == ProposalStatusEnum::New, Error::<T>::CannotAmend);
+
+			   let cid_changed =
+				   ipfs_address.as_ref().map_or(false, |cid| *cid != proposal.ipfs_address);
+			   let template_changed =
+				   template_id.as_ref().map_or(false, |id| *id != proposal.template_id);
+			   ensure!(cid_changed || template_changed, Error::<T>::EmptyAmendment);
+
+			   Amendments::<T>::try_append(
+				   proposal_id,
+				   Amendment {
+					   ipfs_address: proposal.ipfs_address.clone(),
+					   template_id: proposal.template_id.clone(),
+					   amended_at: <frame_system::Pallet<T>>::block_number(),
+				   },
+			   )
+			   .map_err(|_| Error::<T>::TooManyAmendments)?;
+			   // Recenziile s-au referit la varianta veche; recenzenții desemnați rămân, dar
+			   // trebuie să recenzeze din nou
+			   let _ = Reviews::<T>::clear_prefix(proposal_id, T::MaxVoters::get(), None);
+
+			   if let Some(cid) = ipfs_address {
+				   proposal.ipfs_address = cid;
+			   }
+			   if let Some(id) = template_id {
+				   proposal.template_id = id;
+			   }
+			   // Atestarea se referea la conținutul vechi; offchain worker-ul îl verifică din nou
+			   if cid_changed {
+				   ContentChecks::<T>::remove(proposal_id);
+			   }
+
+			   Self::deposit_event(Event::ProposalAmended {
+				   proposal_id,
+				   who: sender.clone(),
+				   ipfs_address: proposal.ipfs_address.clone(),
+				   template_id: proposal.template_id.clone(),
+			   });
+			   Ok(())
+		   })
+	   }
+
+	   /// Desemnează manual recenzenți, pe lângă cei aleși automat la începerea votului.
+	   #[pallet::call_index(10)]
+	   #[pallet::weight(10_000)]
+	   pub fn assign_reviewers(
+		   origin: OriginFor<T>,
+		   proposal_id: u32,
+		   reviewers: Vec<T::AccountId>,
+	   ) -> DispatchResult {
+		   T::TechnicalCommittee::ensure_origin(origin)?;
+		   let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+		   ensure!(Self::is_under_review(&proposal.status), Error::<T>::InvalidProposalStatus);
+
+		   for reviewer in reviewers {
+			   ensure!(T::CommitteeMembers::contains(&reviewer), Error::<T>::NotCommitteeMember);
+			   ensure!(reviewer != proposal.proposer, Error::<T>::ProposerCannotVote);
+			   Self::add_reviewer(proposal_id, reviewer)?;
+		   }
+		   Ok(())
+	   }
+
+	   #[pallet::call_index(11)]
+	   #[pallet::weight(10_000)]
+	   pub fn submit_review(
+		   origin: OriginFor<T>,
+		   proposal_id: u32,
+		   verdict: ReviewVerdict,
+		   score: u8,
+		   // CID-ul raportului complet, în formă text
+		   report: Vec<u8>,
+	   ) -> DispatchResult {
+		   let reviewer = ensure_signed(origin)?;
+		   let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+		   ensure!(Self::is_under_review(&proposal.status), Error::<T>::InvalidProposalStatus);
+		   ensure!(Reviewers::<T>::get(proposal_id).contains(&reviewer), Error::<T>::NotReviewer);
+		   ensure!(!Reviews::<T>::contains_key(proposal_id, &reviewer), Error::<T>::AlreadyReviewed);
+		   ensure!(score <= MAX_REVIEW_SCORE, Error::<T>::InvalidScore);
+		   let report = Self::parse_cid(&report)?;
+
+		   Reviews::<T>::insert(proposal_id, &reviewer, Review {
+			   verdict,
+			   score,
+			   report,
+			   submitted_at: <frame_system::Pallet<T>>::block_number(),
+		   });
+		   Self::deposit_event(Event::ReviewSubmitted { proposal_id, reviewer, verdict, score });
+		   Ok(())
+	   }
+
+	   #[pallet::call_index(12)]
+	   #[pallet::weight(10_000)]
+	   pub fn register_auditor(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+		   T::TechnicalCommittee::ensure_origin(origin)?;
+		   ensure!(!Auditors::<T>::contains_key(&who), Error::<T>::AlreadyAuditor);
+		   ensure!(Auditors::<T>::count() < T::MaxAuditors::get(), Error::<T>::TooManyAuditors);
+
+		   Auditors::<T>::insert(&who, <frame_system::Pallet<T>>::block_number());
+		   Self::deposit_event(Event::AuditorRegistered { who });
+		   Ok(())
+	   }
+
+	   /// Atestările auditorului rămân în stocare, dar nu mai sunt considerate valabile.
+	   #[pallet::call_index(13)]
+	   #[pallet::weight(10_000)]
+	   pub fn remove_auditor(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+		   T::TechnicalCommittee::ensure_origin(origin)?;
+		   Auditors::<T>::take(&who).ok_or(Error::<T>::NotAuditor)?;
+
+		   Self::deposit_event(Event::AuditorRemoved { who });
+		   Ok(())
+	   }
+
+	   /// Atestă auditul versiunii propuse. O atestare nouă de la același auditor o înlocuiește
+	   /// pe cea veche.
+	   #[pallet::call_index(14)]
+	   #[pallet::weight(10_000)]
+	   pub fn attest_audit(
+		   origin: OriginFor<T>,
+		   proposal_id: u32,
+		   // CID-ul raportului, în formă text
+		   report: Vec<u8>,
+		   severities: AuditSeverities,
+		   expires_at: BlockNumberFor<T>,
+	   ) -> DispatchResult {
+		   let auditor = ensure_signed(origin)?;
+		   ensure!(Auditors::<T>::contains_key(&auditor), Error::<T>::NotAuditor);
+		   let proposal = Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+		   ensure!(
+			   !matches!(
+				   proposal.status,
+				   ProposalStatusEnum::Rejected | ProposalStatusEnum::Withdrawn | ProposalStatusEnum::Revoked
+			   ),
+			   Error::<T>::CannotAudit
+		   );
+		   let now = <frame_system::Pallet<T>>::block_number();
+		   ensure!(expires_at > now, Error::<T>::InvalidExpiry);
+		   let report = Self::parse_cid(&report)?;
+
+		   // Atestările auditorilor scoși din registru fac loc celor noi, ca propunerea să nu
+		   // aibă mai multe atestări decât auditori înregistrați
+		   if !Audits::<T>::contains_key(proposal_id, &auditor) &&
+			   Audits::<T>::iter_prefix(proposal_id).count() as u32 >= T::MaxAuditors::get()
+		   {
+			   let stale: Vec<T::AccountId> = Audits::<T>::iter_key_prefix(proposal_id)
+				   .filter(|other| !Auditors::<T>::contains_key(other))
+				   .collect();
+			   for other in stale {
+				   Audits::<T>::remove(proposal_id, other);
+			   }
+		   }
+
+		   Audits::<T>::insert(proposal_id, &auditor, AuditAttestation {
+			   report,
+			   severities,
+			   attested_at: now,
+			   expires_at,
+		   });
+		   Self::deposit_event(Event::AuditAttested { proposal_id, auditor, severities, expires_at });
+		   Ok(())
+	   }
+
+	   #[pallet::call_index(15)]
+	   #[pallet::weight(10_000)]
+	   pub fn set_audit_required(origin: OriginFor<T>, required: bool) -> DispatchResult {
+		   T::TechnicalCommittee::ensure_origin(origin)?;
+		   AuditRequired::<T>::put(required);
+		   Self::deposit_event(Event::AuditRequirementChanged { required });
+		   Ok(())
+	   }
+
+	   /// Readuce o propunere reținută la migrarea v1 (`migrations::v1::HeldProposals`), cu CID-ul,
+	   /// numele și versiunea corectate. Propunerea își păstrează id-ul, propunătorul și șablonul;
+	   /// una aflată în discuție revine la `New`, iar una aprobată intră în catalog.
+	   #[pallet::call_index(16)]
+	   #[pallet::weight(10_000)]
+	   pub fn recover_held_proposal(
+		   origin: OriginFor<T>,
+		   proposal_id: u32,
+		   // CID-ul în formă text, ca la `submit_proposal`
+		   ipfs_address: Vec<u8>,
+		   name: T::ApplicationName,
+		   version: T::ApplicationVersion,
+	   ) -> DispatchResult {
+		   ensure_root(origin)?;
+		   let (held, _) =
+			   migrations::v1::HeldProposals::<T>::get(proposal_id).ok_or(Error::<T>::NotHeld)?;
+		   let ipfs_address = Self::parse_cid(&ipfs_address)?;
+		   SemVer::parse(version.as_ref()).ok_or(Error::<T>::InvalidVersion)?;
+
+		   // Votul nu se reia automat: comitetul o aduce din nou în discuție
+		   let status = match held.status {
+			   ProposalStatusEnum::InDiscussion => ProposalStatusEnum::New,
+			   status => status,
+		   };
+		   let proposal = Proposal {
+			   proposer: held.proposer,
+			   ipfs_address,
+			   name,
+			   version,
+			   template_id: held.template_id,
+			   status: status.clone(),
+			   // În versiunea 0 nu se rezerva niciun depozit
+			   deposit: Zero::zero(),
+			   voting_end: None,
+		   };
+
+		   migrations::v1::HeldProposals::<T>::remove(proposal_id);
+		   StatusHistory::<T>::insert(
+			   proposal_id,
+			   BoundedVec::truncate_from(sp_std::vec![(
+				   status.clone(),
+				   <frame_system::Pallet<T>>::block_number(),
+			   )]),
+		   );
+		   ProposalsByStatus::<T>::insert(&status, proposal_id, ());
+		   ProposalsByProposer::<T>::insert(&proposal.proposer, proposal_id, ());
+		   if status == ProposalStatusEnum::Approved {
+			   Self::add_release(proposal_id, &proposal);
+		   }
+		   Proposals::<T>::insert(proposal_id, proposal);
+		   Self::deposit_event(Event::HeldProposalRecovered { proposal_id, status });
+		   Ok(())
+	   }
+   }
+
+   #[pallet::hooks]
+   impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+	   fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+		   Self::close_expired_votes(now)
+	   }
+
+	   fn offchain_worker(now: BlockNumberFor<T>) {
+		   Self::check_ipfs_content(now);
+	   }
+   }
+
+   // Implementare separată pentru metodele interne
+   impl<T: Config> Pallet<T> {
+	   /// Numărul de voturi "pentru" necesar, calculat din `ApprovalThreshold` și mărimea
+	   /// actuală a comitetului. Nu poate fi niciodată zero.
+	   pub fn approval_threshold() -> u32 {
+		   let threshold = match T::ApprovalThreshold::get() {
+			   VoteThreshold::Proportion(proportion) =>
+				   proportion.mul_ceil(T::CommitteeMembers::count() as u32),
+			   VoteThreshold::Absolute(count) => count,
+		   };
+		   threshold.max(1)
+	   }
+
+	   /// Deschide votul pentru o propunere și programează închiderea lui automată.
+	   fn start_voting(proposal_id: u32, proposal: &mut Proposal<T>) -> DispatchResult {
+		   let earliest = <frame_system::Pallet<T>>::block_number().saturating_add(T::VotingPeriod::get());
+		   let voting_end = Self::schedule_closure(earliest, proposal_id)?;
+		   proposal.voting_end = Some(voting_end);
+
+		   Votes::<T>::insert(proposal_id, VoteRecord {
+			   ayes: 0,
+			   nays: 0,
+			   voters: BoundedVec::new(),
+			   threshold: Self::approval_threshold(),
+		   });
+
+		   Self::assign_reviewers_round_robin(proposal_id, &proposal.proposer);
+		   Self::deposit_event(Event::VotingStarted { proposal_id, voting_end });
+		   Ok(())
+	   }
+
+	   /// Recenziile pot fi desemnate și trimise până la închiderea votului.
+	   fn is_under_review(status: &ProposalStatusEnum) -> bool {
+		   matches!(status, ProposalStatusEnum::New | ProposalStatusEnum::InDiscussion)
+	   }
+
+	   fn add_reviewer(proposal_id: u32, reviewer: T::AccountId) -> DispatchResult {
+		   Reviewers::<T>::try_mutate(proposal_id, |reviewers| -> DispatchResult {
+			   ensure!(!reviewers.contains(&reviewer), Error::<T>::AlreadyReviewer);
+			   reviewers.try_push(reviewer.clone()).map_err(|_| Error::<T>::TooManyReviewers)?;
+			   Ok(())
 		   })?;
+		   Self::deposit_event(Event::ReviewerAssigned { proposal_id, reviewer });
+		   Ok(())
+	   }
+
+	   /// Completează recenzenții până la `MinReviews`, luând membrii comitetului pe rând.
+	   /// Propunătorul și recenzenții deja desemnați sunt săriți.
+	   pub(crate) fn assign_reviewers_round_robin(proposal_id: u32, proposer: &T::AccountId) {
+		   let members = T::CommitteeMembers::sorted_members();
+		   if members.is_empty() {
+			   return;
+		   }
+		   let assigned = Reviewers::<T>::get(proposal_id);
+		   let mut missing = (T::MinReviews::get() as usize).saturating_sub(assigned.len());
+
+		   let start = NextReviewer::<T>::get() as usize % members.len();
+		   let mut next = start;
+		   for offset in 0..members.len() {
+			   if missing == 0 {
+				   break;
+			   }
+			   let index = (start + offset) % members.len();
+			   let member = &members[index];
+			   next = index + 1;
+			   if member == proposer || assigned.contains(member) {
+				   continue;
+			   }
+			   if Self::add_reviewer(proposal_id, member.clone()).is_err() {
+				   break;
+			   }
+			   missing -= 1;
+		   }
+		   NextReviewer::<T>::put((next % members.len()) as u32);
+	   }
+
+	   /// Câte recenzii sunt necesare: `MinReviews`, dar cel mult câți membri ai comitetului pot
+	   /// recenza propunerea (propunătorul nu poate).
+	   pub fn required_reviews(proposer: &T::AccountId) -> u32 {
+		   let eligible = T::CommitteeMembers::sorted_members()
+			   .iter()
+			   .filter(|member| *member != proposer)
+			   .count() as u32;
+		   T::MinReviews::get().min(eligible)
+	   }
+
+	   fn ensure_reviewed(proposal_id: u32, proposer: &T::AccountId) -> DispatchResult {
+		   let reviews = Reviews::<T>::iter_prefix(proposal_id).count() as u32;
+		   ensure!(reviews >= Self::required_reviews(proposer), Error::<T>::NotEnoughReviews);
+		   Ok(())
+	   }
 
-		   // Emite un eveniment pentru schimbarea statusului
-		   Self::deposit_event(Event::ProposalStatusChanged { 
+	   /// Votul poate fi închis (iar propunerea aprobată sau respinsă) doar cu destule recenzii
+	   /// și, dacă propunerea ar fi aprobată, cu auditul cerut de comitet.
+	   fn ensure_can_close(proposal_id: u32, proposal: &Proposal<T>) -> DispatchResult {
+		   Self::ensure_reviewed(proposal_id, &proposal.proposer)?;
+
+		   let approved = Votes::<T>::get(proposal_id).map_or(false, |record| record.ayes >= record.threshold);
+		   if approved {
+			   Self::ensure_audited(proposal_id)?;
+		   }
+		   Ok(())
+	   }
+
+	   fn ensure_audited(proposal_id: u32) -> DispatchResult {
+		   ensure!(
+			   !AuditRequired::<T>::get() || Self::is_audited(proposal_id),
+			   Error::<T>::MissingAudit
+		   );
+		   Ok(())
+	   }
+
+	   /// O atestare este valabilă până la expirare, cât timp auditorul este înregistrat.
+	   pub fn is_valid_audit(auditor: &T::AccountId, attestation: &AuditAttestation<T>) -> bool {
+		   attestation.expires_at > <frame_system::Pallet<T>>::block_number() &&
+			   Auditors::<T>::contains_key(auditor)
+	   }
+
+	   /// Atestările de audit ale versiunii propuse, fiecare cu valabilitatea ei.
+	   pub fn audits_of(proposal_id: u32) -> Vec<(T::AccountId, AuditAttestation<T>, bool)> {
+		   Audits::<T>::iter_prefix(proposal_id)
+			   .map(|(auditor, attestation)| {
+				   let valid = Self::is_valid_audit(&auditor, &attestation);
+				   (auditor, attestation, valid)
+			   })
+			   .collect()
+	   }
+
+	   /// Versiunea propusă are cel puțin o atestare de audit valabilă.
+	   pub fn is_audited(proposal_id: u32) -> bool {
+		   Audits::<T>::iter_prefix(proposal_id)
+			   .any(|(auditor, attestation)| Self::is_valid_audit(&auditor, &attestation))
+	   }
+
+	   /// Numără voturile și aprobă sau respinge propunerea.
+	   fn do_close_voting(proposal_id: u32, proposal: &mut Proposal<T>) -> DispatchResult {
+		   let record = Votes::<T>::take(proposal_id).ok_or(Error::<T>::VotingNotOpen)?;
+		   let approved = record.ayes >= record.threshold;
+
+		   Self::deposit_event(Event::VotingClosed {
 			   proposal_id,
-			   old_status,
-			   new_status 
+			   ayes: record.ayes,
+			   nays: record.nays,
+			   threshold: record.threshold,
+			   approved,
 		   });
 
+		   if approved {
+			   Self::do_approve(proposal_id, proposal)
+		   } else {
+			   Self::do_reject(proposal_id, proposal)
+		   }
+	   }
+
+	   /// Programează închiderea votului la primul bloc începând cu `earliest` care mai are loc:
+	   /// cel mult `MaxAutoClosuresPerBlock` voturi se închid automat într-un bloc. Se caută în
+	   /// cel mult `MAX_CLOSURE_SCHEDULE_BLOCKS` blocuri.
+	   pub(crate) fn schedule_closure(
+		   earliest: BlockNumberFor<T>,
+		   proposal_id: u32,
+	   ) -> Result<BlockNumberFor<T>, Error<T>> {
+		   let mut block = earliest;
+		   for _ in 0..MAX_CLOSURE_SCHEDULE_BLOCKS {
+			   if VotingDeadlines::<T>::try_append(block, proposal_id).is_ok() {
+				   return Ok(block)
+			   }
+			   block = block.saturating_add(One::one());
+		   }
+		   Err(Error::<T>::VotingQueueFull)
+	   }
+
+	   /// Costul maxim al închiderii automate a unui vot. Comitetul și recenziile sunt socotite la
+	   /// `MaxVoters`, atestările de audit la `MaxAuditors`; aprobarea mută versiunea anterioară
+	   /// în `Superseded`, iar prelungirea poate căuta loc în `MAX_CLOSURE_SCHEDULE_BLOCKS` blocuri.
+	   fn closure_weight() -> Weight {
+		   let max_voters = T::MaxVoters::get() as u64;
+		   let max_auditors = T::MaxAuditors::get() as u64;
+		   // Propunerea (citită de două ori), votul, comitetul, recenziile, atestările cu auditorii
+		   // lor, catalogul, versiunea anterioară, prelungirile și termenele de vot
+		   let reads = 3 + 2 * max_voters + 2 * max_auditors + 2 + 1 + MAX_CLOSURE_SCHEDULE_BLOCKS as u64;
+		   // Propunerea cu indexul și istoricul ei, votul, depozitul, catalogul, versiunea
+		   // anterioară cu indexul și istoricul ei, prelungirile și noul termen
+		   let writes = 4 + 1 + 1 + 1 + 4 + 1 + 1;
+		   T::DbWeight::get().reads_writes(reads, writes)
+	   }
+
+	   /// Închide voturile expirate la blocul `now`.
+	   fn close_expired_votes(now: BlockNumberFor<T>) -> Weight {
+		   let db = T::DbWeight::get();
+		   let mut weight = db.reads_writes(1, 1);
+
+		   let expired = VotingDeadlines::<T>::take(now);
+		   if expired.is_empty() {
+			   return weight;
+		   }
+
+		   for proposal_id in expired {
+			   weight = weight.saturating_add(Self::closure_weight());
+			   // Propunerile închise între timp manual sunt ignorate
+			   let open = Proposals::<T>::get(proposal_id)
+				   .map_or(false, |proposal| proposal.status == ProposalStatusEnum::InDiscussion);
+			   if !open {
+				   continue;
+			   }
+			   // Hook-ul nu rulează într-un strat de stocare; o închidere eșuată nu trebuie să
+			   // lase voturile șterse și depozitul rezervat
+			   let result = with_storage_layer(|| {
+				   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
+					   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+					   // Fără destule recenzii sau fără auditul cerut votul se prelungește cu încă o perioadă
+					   if Self::ensure_can_close(proposal_id, proposal).is_err() {
+						   // După ultima prelungire, sau fără loc pentru ea, propunerea este respinsă
+						   if VotingExtensions::<T>::get(proposal_id) >= MAX_VOTING_EXTENSIONS {
+							   return Self::do_expire(proposal_id, proposal)
+						   }
+						   let earliest = now.saturating_add(T::VotingPeriod::get());
+						   let Ok(voting_end) = Self::schedule_closure(earliest, proposal_id) else {
+							   return Self::do_expire(proposal_id, proposal)
+						   };
+						   VotingExtensions::<T>::mutate(proposal_id, |count| count.saturating_inc());
+						   proposal.voting_end = Some(voting_end);
+						   Self::deposit_event(Event::VotingExtended { proposal_id, voting_end });
+						   return Ok(());
+					   }
+					   Self::do_close_voting(proposal_id, proposal)
+				   })
+			   });
+			   if let Err(error) = result {
+				   Self::deposit_event(Event::VotingClosureFailed { proposal_id, error });
+			   }
+		   }
+
+		   weight
+	   }
+
+	   /// Tranzițiile de status permise. Este singurul loc în care se decide dacă o propunere
+	   /// poate trece dintr-un status în altul.
+	   pub fn ensure_transition(
+		   from: &ProposalStatusEnum,
+		   to: &ProposalStatusEnum,
+	   ) -> Result<(), Error<T>> {
+		   use ProposalStatusEnum::*;
+
+		   let (allowed, error) = match to {
+			   New => (false, Error::<T>::CannotReturnToNew),
+			   InDiscussion => (matches!(from, New), Error::<T>::CannotStartDiscussion),
+			   Approved => (matches!(from, New | InDiscussion), Error::<T>::CannotApprove),
+			   Rejected => (matches!(from, New | InDiscussion), Error::<T>::CannotReject),
+			   Withdrawn => (matches!(from, New), Error::<T>::CannotWithdraw),
+			   Deployed => (matches!(from, Approved), Error::<T>::CannotDeploy),
+			   Deprecated => (matches!(from, Approved | Deployed), Error::<T>::CannotDeprecate),
+			   Superseded =>
+				   (matches!(from, Approved | Deployed | Deprecated), Error::<T>::CannotSupersede),
+			   Revoked => (
+				   matches!(from, Approved | Deployed | Deprecated | Superseded),
+				   Error::<T>::CannotRevoke,
+			   ),
+		   };
+		   ensure!(allowed, error);
+		   Ok(())
+	   }
+
+	   /// Schimbă statusul propunerii după validarea tranziției și îl adaugă în istoric.
+	   fn set_status(
+		   proposal_id: u32,
+		   proposal: &mut Proposal<T>,
+		   new_status: ProposalStatusEnum,
+	   ) -> DispatchResult {
+		   Self::ensure_transition(&proposal.status, &new_status)?;
+		   Self::apply_status(proposal_id, proposal, new_status)
+	   }
+
+	   /// Scrie statusul, indexul și istoricul, fără a valida tranziția.
+	   fn apply_status(
+		   proposal_id: u32,
+		   proposal: &mut Proposal<T>,
+		   new_status: ProposalStatusEnum,
+	   ) -> DispatchResult {
+		   // Istoricul este singurul pas care poate eșua, deci se scrie primul
+		   StatusHistory::<T>::try_append(
+			   proposal_id,
+			   (new_status.clone(), <frame_system::Pallet<T>>::block_number()),
+		   )
+		   .map_err(|_| Error::<T>::TooManyStatusChanges)?;
+		   let old_status = sp_std::mem::replace(&mut proposal.status, new_status.clone());
+		   ProposalsByStatus::<T>::remove(&old_status, proposal_id);
+		   ProposalsByStatus::<T>::insert(&new_status, proposal_id, ());
+
+		   Self::deposit_event(Event::ProposalStatusChanged { proposal_id, old_status, new_status });
+		   Ok(())
+	   }
+
+	   /// Marchează propunerea ca aprobată și eliberează depozitul propunătorului.
+	   fn do_approve(proposal_id: u32, proposal: &mut Proposal<T>) -> DispatchResult {
+		   Self::ensure_audited(proposal_id)?;
+		   Self::set_status(proposal_id, proposal, ProposalStatusEnum::Approved)?;
+		   // Votul s-a încheiat; rezultatul rămâne în evenimentul `VotingClosed`
+		   Votes::<T>::remove(proposal_id);
+		   VotingExtensions::<T>::remove(proposal_id);
+
+		   let remaining = T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+		   Self::deposit_event(Event::ProposalDepositReleased {
+			   proposal_id,
+			   who: proposal.proposer.clone(),
+			   amount: proposal.deposit.saturating_sub(remaining),
+		   });
+
+		   // Emite evenimentul cu toate detaliile propunerii
+		   Self::deposit_event(Event::ProposalApproved {
+			   proposal_id,
+			   who: proposal.proposer.clone(),
+			   ipfs_address: proposal.ipfs_address.clone(),
+			   name: proposal.name.clone(),
+			   version: proposal.version.clone(),
+			   template_id: proposal.template_id.clone(),
+		   });
+
+		   Self::add_release(proposal_id, proposal);
+		   Ok(())
+	   }
+
+	   /// Propunerea poate fi instalată doar cât timp este aprobată sau deja instalată.
+	   pub fn is_deployable(status: &ProposalStatusEnum) -> bool {
+		   matches!(status, ProposalStatusEnum::Approved | ProposalStatusEnum::Deployed)
+	   }
+
+	   /// Apelată de alte paleți când prima instanță a aplicației pornește: `Approved` devine
+	   /// `Deployed`. Pentru celelalte statusuri (deja instalată, înlocuită etc.) nu face nimic.
+	   pub fn note_deployed(proposal_id: u32) -> DispatchResult {
+		   Proposals::<T>::try_mutate(proposal_id, |maybe_proposal| -> DispatchResult {
+			   let proposal = maybe_proposal.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+			   if proposal.status != ProposalStatusEnum::Approved {
+				   return Ok(())
+			   }
+			   Self::set_status(proposal_id, proposal, ProposalStatusEnum::Deployed)
+		   })
+	   }
+
+	   /// Adaugă propunerea aprobată în catalogul aplicațiilor, păstrând versiunile ordonate
+	   /// crescător. Dacă noua versiune devine cea mai recentă, versiunea anterioară devine
+	   /// `Superseded`.
+	   fn add_release(proposal_id: u32, proposal: &Proposal<T>) {
+		   let release = ApplicationRelease {
+			   proposal_id,
+			   version: proposal.version.clone(),
+			   ipfs_address: proposal.ipfs_address.clone(),
+			   template_id: proposal.template_id.clone(),
+			   approved_at: <frame_system::Pallet<T>>::block_number(),
+		   };
+
+		   let (previous, dropped) = Applications::<T>::mutate(&proposal.name, |maybe_application| {
+			   match maybe_application {
+				   Some(application) => {
+					   let previous = application.latest_release().map(|r| r.proposal_id);
+					   // Catalogul plin: cea mai veche versiune face loc celei noi
+					   let dropped = if application.releases.is_full() {
+						   Some(application.releases.remove(0).proposal_id)
+					   } else {
+						   None
+					   };
+					   // Versiunile care nu pot fi parsate (dinaintea validării) sunt considerate cele mai vechi
+					   let new_version = SemVer::parse(release.version.as_ref());
+					   let position = application
+						   .releases
+						   .iter()
+						   .position(|r| SemVer::parse(r.version.as_ref()) > new_version)
+						   .unwrap_or(application.releases.len());
+					   let is_latest = position == application.releases.len();
+					   // Există loc după eliminarea de mai sus
+					   let _ = application.releases.try_insert(position, release);
+					   application.latest = application.releases.len().saturating_sub(1) as u32;
+					   (if is_latest { previous } else { None }, dropped)
+				   },
+				   None => {
+					   ApplicationCount::<T>::mutate(|count| count.saturating_inc());
+					   *maybe_application = Some(Application {
+						   publisher: proposal.proposer.clone(),
+						   releases: BoundedVec::truncate_from(sp_std::vec![release]),
+						   latest: 0,
+					   });
+					   (None, None)
+				   },
+			   }
+		   });
+
+		   if let Some(dropped_id) = dropped {
+			   Self::deposit_event(Event::ApplicationReleaseRemoved {
+				   name: proposal.name.clone(),
+				   proposal_id: dropped_id,
+			   });
+		   }
+
+		   if let Some(previous_id) = previous {
+			   // Versiunea anterioară poate fi deja retrasă; în acest caz nu o mai modificăm
+			   let _ = with_storage_layer(|| {
+				   Proposals::<T>::try_mutate(previous_id, |maybe_previous| -> DispatchResult {
+					   let previous = maybe_previous.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+					   Self::set_status(previous_id, previous, ProposalStatusEnum::Superseded)
+				   })
+			   });
+		   }
+
+		   Self::deposit_event(Event::ApplicationReleased {
+			   name: proposal.name.clone(),
+			   version: proposal.version.clone(),
+			   proposal_id,
+		   });
+	   }
+
+	   /// Scoate din catalog versiunea corespunzătoare unei propuneri revocate. Dacă era cea mai
+	   /// recentă, versiunea anterioară redevine cea recomandată și își recapătă statusul.
+	   fn remove_release(proposal_id: u32, name: &T::ApplicationName) {
+		   let (removed, new_latest) = Applications::<T>::mutate_exists(name, |maybe_application| {
+			   let Some(application) = maybe_application else { return (false, None) };
+			   let was_latest =
+				   application.latest_release().map_or(false, |r| r.proposal_id == proposal_id);
+			   let before = application.releases.len();
+			   application.releases.retain(|r| r.proposal_id != proposal_id);
+			   let removed = before != application.releases.len();
+			   if application.releases.is_empty() {
+				   *maybe_application = None;
+				   ApplicationCount::<T>::mutate(|count| count.saturating_dec());
+				   return (removed, None)
+			   }
+			   application.latest = (application.releases.len() - 1) as u32;
+			   let new_latest = application.latest_release().map(|r| r.proposal_id);
+			   (removed, if was_latest { new_latest } else { None })
+		   });
+
+		   if removed {
+			   Self::deposit_event(Event::ApplicationReleaseRemoved { name: name.clone(), proposal_id });
+		   }
+
+		   if let Some(latest_id) = new_latest {
+			   // Un istoric plin nu trebuie să lase indexul de statusuri pe jumătate modificat
+			   let _ = with_storage_layer(|| {
+				   Proposals::<T>::try_mutate(latest_id, |maybe_latest| -> DispatchResult {
+					   let latest = maybe_latest.as_mut().ok_or(Error::<T>::ProposalNotFound)?;
+					   if latest.status != ProposalStatusEnum::Superseded {
+						   return Ok(())
+					   }
+					   // Fără loc pentru încă un ciclu, versiunea rămâne înlocuită; o revocare
+					   // ulterioară trebuie să aibă loc în istoric
+					   let history = StatusHistory::<T>::decode_len(latest_id).unwrap_or(0) as u32;
+					   if history.saturating_add(STATUS_CYCLE_LENGTH) > MaxStatusChanges::get() {
+						   return Ok(())
+					   }
+					   // Singura ieșire din `Superseded` în afară de revocare, deci nu este în
+					   // `ensure_transition`
+					   let restored = Self::status_before_superseded(latest_id);
+					   Self::apply_status(latest_id, latest, restored)
+				   })
+			   });
+		   }
+	   }
+
+	   /// Statusul avut de propunere înainte de a fi înlocuită, din istoricul statusurilor.
+	   /// Propunerile fără istoric (migrate) redevin `Approved`.
+	   fn status_before_superseded(proposal_id: u32) -> ProposalStatusEnum {
+		   StatusHistory::<T>::get(proposal_id)
+			   .iter()
+			   .rev()
+			   .map(|(status, _)| status)
+			   .find(|status| {
+				   matches!(
+					   status,
+					   ProposalStatusEnum::Approved |
+						   ProposalStatusEnum::Deployed |
+						   ProposalStatusEnum::Deprecated
+				   )
+			   })
+			   .cloned()
+			   .unwrap_or(ProposalStatusEnum::Approved)
+	   }
+
+	   /// O pagină din catalog, începând după aplicația `start_after`.
+	   pub fn applications_page(
+		   start_after: Option<T::ApplicationName>,
+		   limit: u32,
+	   ) -> Vec<(T::ApplicationName, Application<T>)> {
+		   let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		   match start_after {
+			   Some(name) => Applications::<T>::iter_from(Applications::<T>::hashed_key_for(name))
+				   .take(limit)
+				   .collect(),
+			   None => Applications::<T>::iter().take(limit).collect(),
+		   }
+	   }
+
+	   /// O pagină din propunerile cu statusul dat, începând după propunerea `start_after`.
+	   /// Al doilea element este cursorul pentru pagina următoare, dacă mai sunt propuneri.
+	   pub fn proposals_by_status(
+		   status: ProposalStatusEnum,
+		   start_after: Option<u32>,
+		   limit: u32,
+	   ) -> (Vec<(u32, Proposal<T>)>, Option<u32>) {
+		   let ids = match start_after {
+			   Some(id) => ProposalsByStatus::<T>::iter_key_prefix_from(
+				   &status,
+				   ProposalsByStatus::<T>::hashed_key_for(&status, id),
+			   ),
+			   None => ProposalsByStatus::<T>::iter_key_prefix(&status),
+		   };
+		   Self::proposals_page(ids, limit)
+	   }
+
+	   /// O pagină din propunerile trimise de `proposer`, începând după propunerea `start_after`.
+	   pub fn proposals_by_proposer(
+		   proposer: &T::AccountId,
+		   start_after: Option<u32>,
+		   limit: u32,
+	   ) -> (Vec<(u32, Proposal<T>)>, Option<u32>) {
+		   let ids = match start_after {
+			   Some(id) => ProposalsByProposer::<T>::iter_key_prefix_from(
+				   proposer,
+				   ProposalsByProposer::<T>::hashed_key_for(proposer, id),
+			   ),
+			   None => ProposalsByProposer::<T>::iter_key_prefix(proposer),
+		   };
+		   Self::proposals_page(ids, limit)
+	   }
+
+	   /// Numărul propunerilor cu statusul dat.
+	   pub fn count_by_status(status: ProposalStatusEnum) -> u32 {
+		   ProposalsByStatus::<T>::iter_key_prefix(&status).count() as u32
+	   }
+
+	   fn proposals_page(
+		   ids: impl Iterator<Item = u32>,
+		   limit: u32,
+	   ) -> (Vec<(u32, Proposal<T>)>, Option<u32>) {
+		   let limit = limit.min(MAX_PAGE_SIZE) as usize;
+		   // Un element în plus arată dacă există o pagină următoare
+		   let mut ids: Vec<u32> = ids.take(limit + 1).collect();
+		   let more = ids.len() > limit;
+		   ids.truncate(limit);
+		   let next = if more { ids.last().copied() } else { None };
+
+		   let proposals = ids
+			   .into_iter()
+			   .filter_map(|id| Proposals::<T>::get(id).map(|proposal| (id, proposal)))
+			   .collect();
+		   (proposals, next)
+	   }
+
+	   /// Verifică disponibilitatea conținutului propunerilor noi și fixează (pin) conținutul
+	   /// propunerilor aprobate, apoi trimite atestările semnate înapoi în lanț.
+	   fn check_ipfs_content(now: BlockNumberFor<T>) {
+		   let signer = Signer::<T, T::AuthorityId>::any_account();
+		   if !signer.can_sign() {
+			   return;
+		   }
+
+		   let api = ipfs::api_url();
+		   let mut checked = 0;
+		   // Doar propunerile din statusurile verificate, prin indexul de statusuri
+		   let candidates = [
+			   ProposalStatusEnum::New,
+			   ProposalStatusEnum::Approved,
+			   ProposalStatusEnum::Deployed,
+		   ]
+		   .into_iter()
+		   .flat_map(ProposalsByStatus::<T>::iter_key_prefix);
+		   for proposal_id in candidates {
+			   if checked >= MAX_CONTENT_CHECKS_PER_BLOCK {
+				   break;
+			   }
+			   let Some(proposal) = Proposals::<T>::get(proposal_id) else { continue };
+
+			   let check = ContentChecks::<T>::get(proposal_id);
+			   let pin = match proposal.status {
+				   // Propunerile noi se verifică până când conținutul devine disponibil
+				   ProposalStatusEnum::New => {
+					   if check.map_or(false, |c| c.available) {
+						   continue;
+					   }
+					   false
+				   },
+				   ProposalStatusEnum::Approved | ProposalStatusEnum::Deployed => {
+					   if check.map_or(false, |c| c.pinned) {
+						   continue;
+					   }
+					   true
+				   },
+				   _ => continue,
+			   };
+			   if !Self::acquire_check_slot(proposal_id, now) {
+				   continue;
+			   }
+			   checked += 1;
+
+			   // În stocare CID-ul este binar; API-ul IPFS primește forma text
+			   let Some(cid) = cid::render(proposal.ipfs_address.as_ref()) else {
+				   log::warn!(target: "runtime::bizix", "proposal {} has an invalid CID", proposal_id);
+				   continue;
+			   };
+			   let size = ipfs::block_stat(&api, &cid).ok();
+			   let available = size.is_some();
+			   let pinned = pin && available && ipfs::pin_add(&api, &cid).is_ok();
+
+			   let result = signer.send_signed_transaction(|_| Call::submit_content_check {
+				   proposal_id,
+				   available,
+				   size,
+				   pinned,
+			   });
+			   if let Some((_, Err(()))) = result {
+				   log::warn!(target: "runtime::bizix", "failed to submit content check for proposal {}", proposal_id);
+			   }
+		   }
+	   }
+
+	   /// Validează CID-ul trimis în formă text și îl întoarce în forma binară canonică.
+	   pub fn parse_cid(text: &[u8]) -> Result<T::IPFSAddress, Error<T>> {
+		   let parsed = Cid::parse(text).map_err(|e| {
+			   if e.is_malformed() { Error::<T>::InvalidCid } else { Error::<T>::UnsupportedCid }
+		   })?;
+		   T::IPFSAddress::try_from(parsed.to_bytes()).map_err(|_| Error::<T>::InvalidCid)
+	   }
+
+	   /// Evită verificarea aceleiași propuneri la fiecare bloc: o propunere se reverifică doar
+	   /// după `CONTENT_CHECK_RETRY_BLOCKS` blocuri.
+	   fn acquire_check_slot(proposal_id: u32, now: BlockNumberFor<T>) -> bool {
+		   let key = (b"bizix::content-check::", proposal_id).encode();
+		   StorageValueRef::persistent(&key)
+			   .mutate(|last: Result<Option<BlockNumberFor<T>>, _>| match last {
+				   Ok(Some(last)) if now < last.saturating_add(CONTENT_CHECK_RETRY_BLOCKS.into()) => Err(()),
+				   _ => Ok(now),
+			   })
+			   .is_ok()
+	   }
+
+	   /// Marchează propunerea ca respinsă; o parte din depozit merge la `Slashed`, restul se
+	   /// returnează propunătorului.
+	   fn do_reject(proposal_id: u32, proposal: &mut Proposal<T>) -> DispatchResult {
+		   Self::set_status(proposal_id, proposal, ProposalStatusEnum::Rejected)?;
+		   Votes::<T>::remove(proposal_id);
+		   VotingExtensions::<T>::remove(proposal_id);
+
+		   let to_slash = T::RejectionSlash::get() * proposal.deposit;
+		   let (imbalance, _) = T::Currency::slash_reserved(&proposal.proposer, to_slash);
+		   let slashed = imbalance.peek();
+		   T::Slashed::on_unbalanced(imbalance);
+
+		   let to_return = proposal.deposit.saturating_sub(slashed);
+		   let remaining = T::Currency::unreserve(&proposal.proposer, to_return);
+		   Self::deposit_event(Event::ProposalDepositSlashed {
+			   proposal_id,
+			   who: proposal.proposer.clone(),
+			   slashed,
+			   returned: to_return.saturating_sub(remaining),
+		   });
+
+		   Self::deposit_event(Event::ProposalRejected { proposal_id });
+		   Ok(())
+	   }
+
+	   /// Respinge propunerea al cărei vot nu s-a putut închide. Lipsa recenziilor sau a
+	   /// auditului nu este vina propunătorului, așa că depozitul se returnează integral.
+	   fn do_expire(proposal_id: u32, proposal: &mut Proposal<T>) -> DispatchResult {
+		   Self::set_status(proposal_id, proposal, ProposalStatusEnum::Rejected)?;
+		   Votes::<T>::remove(proposal_id);
+		   VotingExtensions::<T>::remove(proposal_id);
+
+		   let remaining = T::Currency::unreserve(&proposal.proposer, proposal.deposit);
+		   Self::deposit_event(Event::ProposalDepositReleased {
+			   proposal_id,
+			   who: proposal.proposer.clone(),
+			   amount: proposal.deposit.saturating_sub(remaining),
+		   });
+
+		   Self::deposit_event(Event::VotingExpired { proposal_id });
+		   Self::deposit_event(Event::ProposalRejected { proposal_id });
 		   Ok(())
 	   }
    }
@@ -0,0 +1,452 @@
+//! Migrările stocării bizix-core.
+//!
+//! Fiecare versiune are propriul modul, cu o migrare `VersionedMigration` care rulează doar dacă
+//! versiunea din lanț este cea așteptată. Migrările se adaugă în `Migrations` din runtime.
+
+use crate::{cid::Cid, pallet::*, semver::SemVer};
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, storage_alias, traits::OnRuntimeUpgrade,
+	BoundedVec,
+};
+use sp_runtime::traits::{Saturating, Zero};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+/// Versiunea 1: propunerile primesc depozit, termen de vot, recenzenți, CID binar și istoricul
+/// statusului, cele aprobate intră în catalogul aplicațiilor, voturile devin `VoteRecord`, iar
+/// valorile individuale nefolosite (`ProposalIPFSAddress` etc.) sunt șterse. Propunerile care nu
+/// se pot converti sunt mutate în `HeldProposals`.
+pub mod v1 {
+	use super::*;
+
+	/// Structurile din versiunea 0, așa cum erau codificate în lanț.
+	pub(crate) mod v0 {
+		use super::*;
+
+		#[derive(Encode, Decode, Clone)]
+		pub struct OldProposal<AccountId, TemplateId> {
+			pub proposer: AccountId,
+			// CID-ul în formă text
+			pub ipfs_address: Vec<u8>,
+			pub name: Vec<u8>,
+			pub version: Vec<u8>,
+			pub template_id: TemplateId,
+			// Primele patru variante (`New`..`Rejected`) au aceeași codificare
+			pub status: ProposalStatusEnum,
+		}
+
+		pub type OldProposalOf<T> =
+			OldProposal<<T as frame_system::Config>::AccountId, <T as Config>::ProxmoxTemplateID>;
+
+		#[cfg(any(test, feature = "try-runtime"))]
+		#[storage_alias]
+		pub type Proposals<T: Config> =
+			StorageMap<Pallet<T>, Blake2_128Concat, u32, OldProposalOf<T>>;
+
+		#[storage_alias]
+		pub type ProposalIPFSAddress<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+		#[storage_alias]
+		pub type ProposalName<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+		#[storage_alias]
+		pub type ProposalVersion<T: Config> = StorageValue<Pallet<T>, Vec<u8>>;
+		#[storage_alias]
+		pub type ProposalTemplateID<T: Config> =
+			StorageValue<Pallet<T>, <T as Config>::ProxmoxTemplateID>;
+		#[storage_alias]
+		pub type ProposalStatus<T: Config> = StorageValue<Pallet<T>, ProposalStatusEnum>;
+	}
+
+	/// De ce o propunere veche nu a putut fi migrată.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+	pub enum HoldReason {
+		// Numele, versiunea sau CID-ul depășesc limitele noilor tipuri
+		Bounds,
+		// Adresa nu este un CID valid, deci nu are formă binară canonică
+		InvalidCid,
+	}
+
+	/// Propunerile din versiunea 0 care nu încap în noul format, păstrate neschimbate ca să
+	/// poată fi recuperate de root cu `recover_held_proposal`.
+	#[storage_alias]
+	pub type HeldProposals<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, u32, (v0::OldProposalOf<T>, HoldReason)>;
+
+	/// Convertește o propunere veche sau întoarce motivul pentru care nu se poate.
+	pub(crate) fn migrate_proposal<T: Config>(
+		old: v0::OldProposalOf<T>,
+	) -> Result<Proposal<T>, HoldReason> {
+		let cid = Cid::parse(&old.ipfs_address).map_err(|_| HoldReason::InvalidCid)?;
+		let ipfs_address =
+			T::IPFSAddress::try_from(cid.to_bytes()).map_err(|_| HoldReason::Bounds)?;
+
+		Ok(Proposal {
+			proposer: old.proposer,
+			ipfs_address,
+			// `Vec<u8>` și `BoundedVec<u8, _>` au aceeași codificare
+			name: reencode(&old.name).ok_or(HoldReason::Bounds)?,
+			version: reencode(&old.version).ok_or(HoldReason::Bounds)?,
+			template_id: old.template_id,
+			status: old.status,
+			// În versiunea 0 nu se rezerva niciun depozit
+			deposit: Zero::zero(),
+			voting_end: None,
+		})
+	}
+
+	fn reencode<V: Decode>(value: &impl Encode) -> Option<V> {
+		V::decode(&mut &value.encode()[..]).ok()
+	}
+
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut reads: u64 = 0;
+			let mut writes: u64 = 0;
+
+			v0::ProposalIPFSAddress::<T>::kill();
+			v0::ProposalName::<T>::kill();
+			v0::ProposalVersion::<T>::kill();
+			v0::ProposalTemplateID::<T>::kill();
+			v0::ProposalStatus::<T>::kill();
+			writes.saturating_accrue(5);
+
+			// Propunerile aflate în discuție primesc un termen de vot, ca și cum votul ar fi
+			// început acum
+			let now = <frame_system::Pallet<T>>::block_number();
+			let mut migrated: u32 = 0;
+			let mut held: u32 = 0;
+			let mut in_discussion: Vec<u32> = Vec::new();
+			let mut approved: Vec<(u32, Proposal<T>)> = Vec::new();
+			Proposals::<T>::translate::<v0::OldProposalOf<T>, _>(|proposal_id, old| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+
+				let discussed = old.status == ProposalStatusEnum::InDiscussion;
+				let mut proposal = match migrate_proposal::<T>(old.clone()) {
+					Ok(proposal) => proposal,
+					Err(reason) => {
+						held.saturating_inc();
+						writes.saturating_inc();
+						log::warn!(
+							target: "runtime::bizix",
+							"holding proposal {} that cannot be migrated: {:?}",
+							proposal_id,
+							reason,
+						);
+						HeldProposals::<T>::insert(proposal_id, (old, reason));
+						return None
+					},
+				};
+				// Istoricul începe cu statusul din momentul migrării
+				StatusHistory::<T>::insert(
+					proposal_id,
+					BoundedVec::truncate_from(sp_std::vec![(proposal.status.clone(), now)]),
+				);
+				writes.saturating_inc();
+				if discussed {
+					// Fără loc în coada de închidere votul rămâne deschis până la închiderea
+					// manuală; `post_upgrade` semnalează acest caz
+					proposal.voting_end = Pallet::<T>::schedule_closure(
+						now.saturating_add(T::VotingPeriod::get()),
+						proposal_id,
+					)
+					.ok();
+					// Recenzenții desemnați fac posibilă închiderea votului cu `MinReviews`
+					Pallet::<T>::assign_reviewers_round_robin(proposal_id, &proposal.proposer);
+					in_discussion.push(proposal_id);
+				}
+				if proposal.status == ProposalStatusEnum::Approved {
+					approved.push((proposal_id, proposal.clone()));
+				}
+				migrated.saturating_inc();
+				Some(proposal)
+			});
+
+			// În versiunea 0 se păstrau doar voturile "pentru". Voturile propunerilor care nu mai
+			// sunt în discuție nu mai sunt folosite.
+			let threshold = Pallet::<T>::approval_threshold();
+			Votes::<T>::translate::<Vec<T::AccountId>, _>(|proposal_id, voters| {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				if !in_discussion.contains(&proposal_id) {
+					return None
+				}
+				let voters: BoundedVec<_, T::MaxVoters> = BoundedVec::truncate_from(
+					voters.into_iter().map(|voter| (voter, true)).collect::<Vec<_>>(),
+				);
+				Some(VoteRecord { ayes: voters.len() as u32, nays: 0, voters, threshold })
+			});
+			// Propunerile fără niciun vot primesc o evidență goală
+			for proposal_id in in_discussion {
+				reads.saturating_inc();
+				if !Votes::<T>::contains_key(proposal_id) {
+					writes.saturating_inc();
+					Votes::<T>::insert(
+						proposal_id,
+						VoteRecord { ayes: 0, nays: 0, voters: BoundedVec::new(), threshold },
+					);
+				}
+				// Termenul de vot
+				writes.saturating_inc();
+			}
+
+			// Versiunile aprobate intră în catalog în ordine crescătoare, ca la aprobare: fiecare
+			// devine cea mai recentă, iar cea anterioară devine `Superseded`. Indexul de statusuri
+			// este construit abia de `v2`, deci statusul se schimbă direct.
+			approved.sort_by_key(|(proposal_id, proposal)| {
+				(SemVer::parse(proposal.version.as_ref()), *proposal_id)
+			});
+			for (proposal_id, proposal) in approved {
+				reads.saturating_inc();
+				writes.saturating_inc();
+				let release = ApplicationRelease {
+					proposal_id,
+					version: proposal.version.clone(),
+					ipfs_address: proposal.ipfs_address.clone(),
+					template_id: proposal.template_id.clone(),
+					approved_at: now,
+				};
+				let previous = Applications::<T>::mutate(&proposal.name, |maybe_application| {
+					match maybe_application {
+						Some(application) => {
+							let previous = application.latest_release().map(|r| r.proposal_id);
+							if application.releases.is_full() {
+								application.releases.remove(0);
+							}
+							// Există loc după eliminarea de mai sus
+							let _ = application.releases.try_push(release);
+							application.latest =
+								application.releases.len().saturating_sub(1) as u32;
+							previous
+						},
+						None => {
+							*maybe_application = Some(Application {
+								publisher: proposal.proposer.clone(),
+								releases: BoundedVec::truncate_from(sp_std::vec![release]),
+								latest: 0,
+							});
+							None
+						},
+					}
+				});
+				if let Some(previous_id) = previous {
+					reads.saturating_accrue(2);
+					writes.saturating_accrue(2);
+					Proposals::<T>::mutate(previous_id, |maybe_previous| {
+						if let Some(previous) = maybe_previous {
+							previous.status = ProposalStatusEnum::Superseded;
+						}
+					});
+					let _ = StatusHistory::<T>::try_append(
+						previous_id,
+						(ProposalStatusEnum::Superseded, now),
+					);
+				}
+			}
+
+			log::info!(
+				target: "runtime::bizix",
+				"migrated {} proposals to v1, held {}",
+				migrated,
+				held,
+			);
+			T::DbWeight::get().reads_writes(reads, writes)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(HeldProposals::<T>::iter_keys().next().is_none(), "held proposals not empty");
+			let total = v0::Proposals::<T>::iter_keys().count() as u32;
+			let expected = v0::Proposals::<T>::iter()
+				.filter(|(_, old)| migrate_proposal::<T>(old.clone()).is_ok())
+				.count() as u32;
+			Ok((total, expected).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (total, expected) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+
+			// `iter` sare peste intrările care nu se pot decoda
+			let migrated = Proposals::<T>::iter().count() as u32;
+			ensure!(migrated == expected, "some proposals were not migrated");
+			let held = HeldProposals::<T>::iter().count() as u32;
+			ensure!(migrated.saturating_add(held) == total, "some proposals were lost");
+			ensure!(
+				Proposals::<T>::iter_keys().count() as u32 == migrated,
+				"undecodable proposals left in storage"
+			);
+			ensure!(
+				Votes::<T>::iter_keys().count() == Votes::<T>::iter().count(),
+				"undecodable vote records left in storage"
+			);
+			for (proposal_id, proposal) in Proposals::<T>::iter() {
+				ensure!(
+					StatusHistory::<T>::get(proposal_id).last().map(|(status, _)| status) ==
+						Some(&proposal.status),
+					"proposal without status history"
+				);
+				if proposal.status == ProposalStatusEnum::InDiscussion {
+					ensure!(
+						proposal.voting_end.is_some(),
+						"proposal in discussion without deadline"
+					);
+					ensure!(Votes::<T>::contains_key(proposal_id), "proposal without vote record");
+					ensure!(
+						Reviewers::<T>::decode_len(proposal_id).unwrap_or(0) as u32 >=
+							Pallet::<T>::required_reviews(&proposal.proposer),
+						"proposal in discussion without enough reviewers"
+					);
+				}
+				if proposal.status == ProposalStatusEnum::Approved {
+					ensure!(
+						Applications::<T>::get(&proposal.name)
+							.and_then(|application| application.latest_release().cloned())
+							.map(|release| release.proposal_id) ==
+							Some(proposal_id),
+						"approved proposal is not the latest release in the catalog"
+					);
+				}
+			}
+			for (_, application) in Applications::<T>::iter() {
+				for release in application.releases.iter() {
+					ensure!(
+						Proposals::<T>::contains_key(release.proposal_id),
+						"catalog release without proposal"
+					);
+				}
+			}
+			// `recover_held_proposal` refolosește id-ul, care nu trebuie să fie luat de o
+			// propunere nouă
+			let next_id = CurrentProposalId::<T>::get();
+			for proposal_id in HeldProposals::<T>::iter_keys() {
+				ensure!(!Proposals::<T>::contains_key(proposal_id), "held proposal not removed");
+				ensure!(proposal_id < next_id, "held proposal id can be reused");
+			}
+			ensure!(!v0::ProposalIPFSAddress::<T>::exists(), "ProposalIPFSAddress not removed");
+			ensure!(!v0::ProposalName::<T>::exists(), "ProposalName not removed");
+			ensure!(!v0::ProposalVersion::<T>::exists(), "ProposalVersion not removed");
+			ensure!(!v0::ProposalTemplateID::<T>::exists(), "ProposalTemplateID not removed");
+			ensure!(!v0::ProposalStatus::<T>::exists(), "ProposalStatus not removed");
+			Ok(())
+		}
+	}
+
+	/// Rulează `VersionUncheckedMigrateToV1` doar dacă versiunea din lanț este 0, apoi o
+	/// actualizează la 1.
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		VersionUncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Versiunea 2: indexurile `ProposalsByStatus` și `ProposalsByProposer`, construite din
+/// propunerile existente.
+pub mod v2 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut indexed: u64 = 0;
+			for (proposal_id, proposal) in Proposals::<T>::iter() {
+				ProposalsByStatus::<T>::insert(&proposal.status, proposal_id, ());
+				ProposalsByProposer::<T>::insert(&proposal.proposer, proposal_id, ());
+				indexed.saturating_inc();
+			}
+
+			log::info!(target: "runtime::bizix", "indexed {} proposals", indexed);
+			T::DbWeight::get().reads_writes(indexed, indexed.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			ensure!(ProposalsByStatus::<T>::iter_keys().next().is_none(), "status index not empty");
+			ensure!(
+				ProposalsByProposer::<T>::iter_keys().next().is_none(),
+				"proposer index not empty"
+			);
+			Ok((Proposals::<T>::iter_keys().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let expected = u32::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+
+			ensure!(
+				ProposalsByStatus::<T>::iter_keys().count() as u32 == expected,
+				"status index does not cover all proposals"
+			);
+			ensure!(
+				ProposalsByProposer::<T>::iter_keys().count() as u32 == expected,
+				"proposer index does not cover all proposals"
+			);
+			for (proposal_id, proposal) in Proposals::<T>::iter() {
+				ensure!(
+					ProposalsByStatus::<T>::contains_key(&proposal.status, proposal_id),
+					"proposal missing from status index"
+				);
+				ensure!(
+					ProposalsByProposer::<T>::contains_key(&proposal.proposer, proposal_id),
+					"proposal missing from proposer index"
+				);
+			}
+			Ok(())
+		}
+	}
+
+	/// Rulează `VersionUncheckedMigrateToV2` doar dacă versiunea din lanț este 1, apoi o
+	/// actualizează la 2.
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Versiunea 3: contorul `ApplicationCount`, numărat din catalogul existent.
+pub mod v3 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let count = Applications::<T>::iter_keys().count() as u32;
+			ApplicationCount::<T>::put(count);
+
+			log::info!(target: "runtime::bizix", "counted {} applications", count);
+			T::DbWeight::get().reads_writes(count.into(), 1)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(_: Vec<u8>) -> Result<(), TryRuntimeError> {
+			ensure!(
+				ApplicationCount::<T>::get() == Applications::<T>::iter_keys().count() as u32,
+				"application count does not match the catalog"
+			);
+			Ok(())
+		}
+	}
+
+	/// Rulează `VersionUncheckedMigrateToV3` doar dacă versiunea din lanț este 2, apoi o
+	/// actualizează la 3.
+	pub type MigrateToV3<T> = VersionedMigration<
+		2,
+		3,
+		VersionUncheckedMigrateToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -1,12 +1,15 @@
 use crate as bizix_core;
 use frame_support::{
-	derive_impl,
-	traits::{ConstU16, ConstU64},
+	derive_impl, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, SortedMembers},
+	BoundedVec,
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
+	testing::TestXt,
 	traits::{BlakeTwo256, IdentityLookup},
-	BuildStorage,
+	BuildStorage, Perbill,
 };
 
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -16,7 +19,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		TemplateModule: bizix_core,
+		Balances: pallet_balances,
+		BizixCore: bizix_core,
 	}
 );
 
@@ -38,21 +42,84 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
+parameter_types! {
+	pub const ProposalPrice: u64 = 100;
+	pub const RejectionSlash: Perbill = Perbill::from_percent(20);
+	pub const ApprovalThreshold: bizix_core::VoteThreshold =
+		bizix_core::VoteThreshold::Proportion(Perbill::from_percent(50));
+	pub Committee: Vec<u64> = vec![2, 3, 4, 5];
+	// Zero implicit; testele pentru recenzii îl modifică
+	pub static MinReviews: u32 = 0;
+}
+
+pub struct CommitteeMembers;
+impl SortedMembers<u64> for CommitteeMembers {
+	fn sorted_members() -> Vec<u64> {
+		Committee::get()
+	}
 }
 
 impl bizix_core::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
+	type IPFSAddress = BoundedVec<u8, ConstU32<64>>;
+	type ApplicationName = BoundedVec<u8, ConstU32<32>>;
+	type ApplicationVersion = BoundedVec<u8, ConstU32<16>>;
+	type ProxmoxTemplateID = u32;
+	type TechnicalCommittee = EnsureRoot<u64>;
+	type Currency = Balances;
+	type ProposalPrice = ProposalPrice;
+	type RejectionSlash = RejectionSlash;
+	type Slashed = ();
+	type CommitteeMembers = CommitteeMembers;
+	type ApprovalThreshold = ApprovalThreshold;
+	type VotingPeriod = ConstU64<10>;
+	type MaxAutoClosuresPerBlock = ConstU32<2>;
+	type MaxVoters = ConstU32<4>;
+	type MaxReleases = ConstU32<3>;
+	type MinReviews = MinReviews;
+	type MaxAuditors = ConstU32<2>;
+	type AuthorityId = bizix_core::testing::TestAuthorityId;
+	type Attesters = CommitteeMembers;
 }
 
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+crate::impl_offchain_test_types!(Test, RuntimeCall);
+
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
@@ -0,0 +1,133 @@
+//! Versiuni semantice (SemVer 2.0.0) pentru `ApplicationVersion`.
+//!
+//! Parsarea se face direct pe octeți, fără alocări în afară de identificatorii de pre-release.
+//! Metadatele de build (`+...`) sunt validate, dar ignorate la comparare, conform specificației.
+
+use sp_std::{cmp::Ordering, vec::Vec};
+
+/// Un identificator de pre-release: numeric (`1`) sau alfanumeric (`rc`, `beta-2`).
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Identifier {
+	Numeric(u64),
+	AlphaNumeric(Vec<u8>),
+}
+
+impl Ord for Identifier {
+	fn cmp(&self, other: &Self) -> Ordering {
+		match (self, other) {
+			(Identifier::Numeric(a), Identifier::Numeric(b)) => a.cmp(b),
+			(Identifier::AlphaNumeric(a), Identifier::AlphaNumeric(b)) => a.cmp(b),
+			// Identificatorii numerici au întotdeauna precedență mai mică
+			(Identifier::Numeric(_), Identifier::AlphaNumeric(_)) => Ordering::Less,
+			(Identifier::AlphaNumeric(_), Identifier::Numeric(_)) => Ordering::Greater,
+		}
+	}
+}
+
+impl PartialOrd for Identifier {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+/// O versiune `MAJOR.MINOR.PATCH[-PRE][+BUILD]`.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct SemVer {
+	pub major: u64,
+	pub minor: u64,
+	pub patch: u64,
+	pub pre: Vec<Identifier>,
+}
+
+impl SemVer {
+	/// Parsează o versiune; întoarce `None` dacă nu respectă SemVer 2.0.0.
+	pub fn parse(input: &[u8]) -> Option<Self> {
+		// Metadatele de build nu participă la ordonare, dar trebuie să fie bine formate
+		let (rest, build) = split_once(input, b'+');
+		if let Some(build) = build {
+			for ident in build.split(|c| *c == b'.') {
+				if ident.is_empty() || !ident.iter().all(is_ident_char) {
+					return None
+				}
+			}
+		}
+
+		let (core, pre) = split_once(rest, b'-');
+		let mut parts = core.split(|c| *c == b'.');
+		let major = parse_numeric(parts.next()?)?;
+		let minor = parse_numeric(parts.next()?)?;
+		let patch = parse_numeric(parts.next()?)?;
+		if parts.next().is_some() {
+			return None
+		}
+
+		let pre = match pre {
+			Some(pre) => pre.split(|c| *c == b'.').map(parse_identifier).collect::<Option<Vec<_>>>()?,
+			None => Vec::new(),
+		};
+
+		Some(SemVer { major, minor, patch, pre })
+	}
+
+	pub fn is_prerelease(&self) -> bool {
+		!self.pre.is_empty()
+	}
+}
+
+impl Ord for SemVer {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.major
+			.cmp(&other.major)
+			.then(self.minor.cmp(&other.minor))
+			.then(self.patch.cmp(&other.patch))
+			.then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+				(true, true) => Ordering::Equal,
+				// O versiune de pre-release precedă versiunea finală
+				(true, false) => Ordering::Greater,
+				(false, true) => Ordering::Less,
+				// Comparare pe identificatori; la prefix comun, lista mai scurtă e mai mică
+				(false, false) => self.pre.cmp(&other.pre),
+			})
+	}
+}
+
+impl PartialOrd for SemVer {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+fn split_once(input: &[u8], separator: u8) -> (&[u8], Option<&[u8]>) {
+	match input.iter().position(|c| *c == separator) {
+		Some(index) => (&input[..index], Some(&input[index + 1..])),
+		None => (input, None),
+	}
+}
+
+fn is_ident_char(c: &u8) -> bool {
+	c.is_ascii_alphanumeric() || *c == b'-'
+}
+
+// Numerele nu pot fi goale și nu pot avea zerouri la început
+fn parse_numeric(input: &[u8]) -> Option<u64> {
+	if input.is_empty() || !input.iter().all(u8::is_ascii_digit) {
+		return None
+	}
+	if input.len() > 1 && input[0] == b'0' {
+		return None
+	}
+	input
+		.iter()
+		.try_fold(0u64, |acc, c| acc.checked_mul(10)?.checked_add(u64::from(c - b'0')))
+}
+
+fn parse_identifier(input: &[u8]) -> Option<Identifier> {
+	if input.is_empty() || !input.iter().all(is_ident_char) {
+		return None
+	}
+	if input.iter().all(u8::is_ascii_digit) {
+		parse_numeric(input).map(Identifier::Numeric)
+	} else {
+		Some(Identifier::AlphaNumeric(input.to_vec()))
+	}
+}
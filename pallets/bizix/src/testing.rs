@@ -0,0 +1,59 @@
+//! Tipuri comune pentru runtime-urile de test ale paletelor care depind de bizix-core.
+//!
+//! `Config` cere `CreateSignedTransaction`, deci fiecare mock are nevoie de tipurile de semnare
+//! offchain. `impl_offchain_test_types!` le implementează cu cheile de test `UintAuthorityId`.
+
+use sp_runtime::testing::{TestSignature, UintAuthorityId};
+
+#[doc(hidden)]
+pub use {frame_system, sp_runtime};
+
+// Cheile de test (`UintAuthorityId`) sunt chiar conturile atestatorilor
+pub struct TestAuthorityId;
+impl frame_system::offchain::AppCrypto<UintAuthorityId, TestSignature> for TestAuthorityId {
+	type RuntimeAppPublic = UintAuthorityId;
+	type GenericPublic = UintAuthorityId;
+	type GenericSignature = TestSignature;
+}
+
+/// Implementează tipurile de semnare offchain pentru runtime-ul de test `$runtime`, cu
+/// extrinsecele `TestXt<$call, ()>`. Runtime-ul trebuie să folosească `Nonce = u64`.
+#[macro_export]
+macro_rules! impl_offchain_test_types {
+	($runtime:ty, $call:ty) => {
+		impl $crate::testing::frame_system::offchain::SigningTypes for $runtime {
+			type Public = $crate::testing::sp_runtime::testing::UintAuthorityId;
+			type Signature = $crate::testing::sp_runtime::testing::TestSignature;
+		}
+
+		impl<LocalCall> $crate::testing::frame_system::offchain::SendTransactionTypes<LocalCall>
+			for $runtime
+		where
+			$call: From<LocalCall>,
+		{
+			type OverarchingCall = $call;
+			type Extrinsic = $crate::testing::sp_runtime::testing::TestXt<$call, ()>;
+		}
+
+		impl<LocalCall> $crate::testing::frame_system::offchain::CreateSignedTransaction<LocalCall>
+			for $runtime
+		where
+			$call: From<LocalCall>,
+		{
+			fn create_transaction<
+				C: $crate::testing::frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+			>(
+				call: $call,
+				_public: $crate::testing::sp_runtime::testing::UintAuthorityId,
+				_account: <$runtime as $crate::testing::frame_system::Config>::AccountId,
+				nonce: u64,
+			) -> Option<(
+				$call,
+				<$crate::testing::sp_runtime::testing::TestXt<$call, ()> as
+					$crate::testing::sp_runtime::traits::Extrinsic>::SignaturePayload,
+			)> {
+				Some((call, (nonce, ())))
+			}
+		}
+	};
+}
@@ -1,27 +1,1595 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	cid::{self, Cid, CidError},
+	ipfs,
+	mock::*,
+	semver::{Identifier, SemVer},
+	ApplicationCount, Applications, AuditSeverities, ContentChecks, Error, Event, MaxStatusChanges,
+	ProposalStatusEnum, Proposals, ProposalsByProposer, ProposalsByStatus, ReviewVerdict,
+	StatusHistory, Votes, VotingDeadlines, MAX_CLOSURE_SCHEDULE_BLOCKS, MAX_REVIEW_SCORE,
+	MAX_VOTING_EXTENSIONS, STATUS_CYCLE_LENGTH,
+};
+use codec::Decode;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use sp_core::offchain::{
+	testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+	OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+};
+use sp_runtime::{testing::UintAuthorityId, DispatchError, DispatchResult};
+
+fn app_name() -> <Test as crate::Config>::ApplicationName {
+	BoundedVec::truncate_from(b"wordpress".to_vec())
+}
+
+fn submit(who: u64) -> u32 {
+	submit_version(who, b"1.0.0")
+}
+
+fn submit_version(who: u64, version: &[u8]) -> u32 {
+	let proposal_id = BizixCore::current_proposal_id();
+	assert_ok!(BizixCore::submit_proposal(
+		RuntimeOrigin::signed(who),
+		b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		app_name(),
+		BoundedVec::truncate_from(version.to_vec()),
+		7,
+	));
+	proposal_id
+}
+
+#[test]
+fn submit_proposal_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_eq!(Balances::reserved_balance(1), ProposalPrice::get());
+		assert_eq!(Proposals::<Test>::get(proposal_id).unwrap().deposit, ProposalPrice::get());
+		System::assert_last_event(
+			Event::ProposalDepositReserved { proposal_id, who: 1, amount: ProposalPrice::get() }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn submit_proposal_fails_without_funds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BizixCore::submit_proposal(
+				RuntimeOrigin::signed(42),
+				b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+				app_name(),
+				BoundedVec::truncate_from(b"1.0.0".to_vec()),
+				7,
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn approval_releases_deposit() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Approved
+		);
+	});
+}
+
+#[test]
+fn rejection_slashes_part_of_deposit() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_ok!(BizixCore::reject_proposal(RuntimeOrigin::root(), proposal_id));
+
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000 - 20);
+		System::assert_has_event(
+			Event::ProposalDepositSlashed { proposal_id, who: 1, slashed: 20, returned: 80 }.into(),
+		);
+	});
+}
+
+#[test]
+fn withdraw_refunds_full_deposit() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::withdraw_proposal(RuntimeOrigin::signed(2), proposal_id),
+			Error::<Test>::NotProposer
+		);
+		assert_ok!(BizixCore::withdraw_proposal(RuntimeOrigin::signed(1), proposal_id));
+
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Withdrawn
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		System::assert_last_event(
+			Event::ProposalDepositRefunded { proposal_id, who: 1, amount: ProposalPrice::get() }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn proposer_can_amend_new_proposal() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+		let old_cid = Proposals::<Test>::get(proposal_id).unwrap().ipfs_address;
+		assert_ok!(BizixCore::submit_content_check(
+			RuntimeOrigin::signed(5),
+			proposal_id,
+			true,
+			None,
+			false
+		));
+
+		assert_noop!(
+			BizixCore::amend_proposal(RuntimeOrigin::signed(2), proposal_id, None, Some(8)),
+			Error::<Test>::NotProposer
+		);
+		assert_noop!(
+			BizixCore::amend_proposal(RuntimeOrigin::signed(1), proposal_id, None, Some(7)),
+			Error::<Test>::EmptyAmendment
+		);
+		assert_noop!(
+			BizixCore::amend_proposal(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				Some(b"not a cid".to_vec()),
+				None
+			),
+			Error::<Test>::InvalidCid
+		);
+
+		System::set_block_number(4);
+		assert_ok!(BizixCore::amend_proposal(
+			RuntimeOrigin::signed(1),
+			proposal_id,
+			Some(CID_V1.to_vec()),
+			Some(8)
+		));
+
+		let proposal = Proposals::<Test>::get(proposal_id).unwrap();
+		let new_cid = Cid::parse(CID_V1).unwrap().to_bytes();
+		assert_eq!((proposal.ipfs_address.to_vec(), proposal.template_id), (new_cid, 8));
+		let history = BizixCore::amendments(proposal_id);
+		assert_eq!(history.len(), 1);
+		assert_eq!((history[0].ipfs_address.clone(), history[0].template_id), (old_cid, 7));
+		assert_eq!(history[0].amended_at, 4);
+		// Conținutul nou trebuie verificat din nou
+		assert!(ContentChecks::<Test>::get(proposal_id).is_none());
+		System::assert_last_event(
+			Event::ProposalAmended {
+				proposal_id,
+				who: 1,
+				ipfs_address: proposal.ipfs_address,
+				template_id: 8,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn proposals_cannot_be_amended_after_leaving_new() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_for_voting(1);
+		assert_noop!(
+			BizixCore::amend_proposal(RuntimeOrigin::signed(1), proposal_id, None, Some(8)),
+			Error::<Test>::CannotAmend
+		);
+
+		let withdrawn = submit(1);
+		assert_ok!(BizixCore::withdraw_proposal(RuntimeOrigin::signed(1), withdrawn));
+		assert_noop!(
+			BizixCore::amend_proposal(RuntimeOrigin::signed(1), withdrawn, None, Some(8)),
+			Error::<Test>::CannotAmend
+		);
+	});
+}
+
+#[test]
+fn decisions_require_technical_committee() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::approve_proposal(RuntimeOrigin::signed(2), proposal_id),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			BizixCore::reject_proposal(RuntimeOrigin::signed(2), proposal_id),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			BizixCore::change_proposal_status(
+				RuntimeOrigin::signed(1),
+				proposal_id,
+				ProposalStatusEnum::InDiscussion
+			),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+fn submit_for_voting(who: u64) -> u32 {
+	start_voting_on(submit(who))
+}
+
+fn start_voting_on(proposal_id: u32) -> u32 {
+	assert_ok!(BizixCore::change_proposal_status(
+		RuntimeOrigin::root(),
+		proposal_id,
+		ProposalStatusEnum::InDiscussion
+	));
+	proposal_id
+}
+
+#[test]
+fn votes_are_tallied_against_threshold() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_for_voting(1);
+		// 50% din 4 membri
+		assert_eq!(Votes::<Test>::get(proposal_id).unwrap().threshold, 2);
+
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(3), proposal_id, false));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(4), proposal_id, true));
+
+		let record = Votes::<Test>::get(proposal_id).unwrap();
+		assert_eq!((record.ayes, record.nays), (2, 1));
+
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), proposal_id));
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Approved
+		);
+	});
+}
+
+#[test]
+fn voters_can_change_but_not_repeat_their_vote() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_for_voting(1);
+
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, false));
+		assert_noop!(
+			BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, false),
+			Error::<Test>::AlreadyVoted
+		);
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true));
+
+		let record = Votes::<Test>::get(proposal_id).unwrap();
+		assert_eq!((record.ayes, record.nays), (1, 0));
+		assert_eq!(record.voters, vec![(2, true)]);
+
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), proposal_id));
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Rejected
+		);
+	});
+}
+
+#[test]
+fn proposer_and_outsiders_cannot_vote() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_for_voting(2);
+
+		assert_noop!(
+			BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true),
+			Error::<Test>::ProposerCannotVote
+		);
+		assert_noop!(
+			BizixCore::vote_on_proposal(RuntimeOrigin::signed(1), proposal_id, true),
+			Error::<Test>::NotCommitteeMember
+		);
+	});
+}
+
+#[test]
+fn expired_votes_are_closed_automatically() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_for_voting(1);
+		let voting_end = Proposals::<Test>::get(proposal_id).unwrap().voting_end.unwrap();
+		assert_eq!(voting_end, 11);
+
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(3), proposal_id, true));
+
+		System::set_block_number(voting_end);
+		BizixCore::on_initialize(voting_end);
+
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Approved
+		);
+		assert!(VotingDeadlines::<Test>::get(voting_end).is_empty());
+		assert!(Votes::<Test>::get(proposal_id).is_none());
+	});
+}
+
+#[test]
+fn failed_auto_closures_are_rolled_back() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_for_voting(1);
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true));
+		// Un istoric plin face ca schimbarea statusului să eșueze după ștergerea voturilor
+		StatusHistory::<Test>::mutate(proposal_id, |history| {
+			while history.try_push((ProposalStatusEnum::InDiscussion, 1)).is_ok() {}
+		});
+
+		BizixCore::on_initialize(11);
+
+		System::assert_last_event(
+			Event::VotingClosureFailed {
+				proposal_id,
+				error: Error::<Test>::TooManyStatusChanges.into(),
+			}
+			.into(),
+		);
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::InDiscussion
+		);
+		assert_eq!(Votes::<Test>::get(proposal_id).unwrap().ayes, 1);
+		assert_eq!(Balances::reserved_balance(1), ProposalPrice::get());
+	});
+}
+
+#[test]
+fn auto_closing_is_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		let ids: Vec<u32> = (0..3).map(|_| submit_for_voting(1)).collect();
+
+		// Doar două voturi se pot închide în blocul 11, al treilea este programat în blocul 12
+		assert_eq!(Proposals::<Test>::get(ids[1]).unwrap().voting_end, Some(11));
+		assert_eq!(Proposals::<Test>::get(ids[2]).unwrap().voting_end, Some(12));
+
+		BizixCore::on_initialize(11);
+
+		assert_eq!(Proposals::<Test>::get(ids[0]).unwrap().status, ProposalStatusEnum::Rejected);
+		assert_eq!(Proposals::<Test>::get(ids[1]).unwrap().status, ProposalStatusEnum::Rejected);
+		assert_eq!(
+			Proposals::<Test>::get(ids[2]).unwrap().status,
+			ProposalStatusEnum::InDiscussion
+		);
+		assert_eq!(VotingDeadlines::<Test>::get(12), vec![ids[2]]);
+
+		BizixCore::on_initialize(12);
+		assert_eq!(Proposals::<Test>::get(ids[2]).unwrap().status, ProposalStatusEnum::Rejected);
+	});
+}
+
+#[test]
+fn voting_cannot_start_when_the_closure_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		// Toate blocurile în care s-ar putea închide votul sunt ocupate
+		for block in 11..11 + MAX_CLOSURE_SCHEDULE_BLOCKS as u64 {
+			VotingDeadlines::<Test>::insert(block, BoundedVec::truncate_from(vec![100, 101]));
+		}
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::change_proposal_status(
+				RuntimeOrigin::root(),
+				proposal_id,
+				ProposalStatusEnum::InDiscussion
+			),
+			Error::<Test>::VotingQueueFull
+		);
+	});
+}
+
+fn review(reviewer: u64, proposal_id: u32, verdict: ReviewVerdict, score: u8) -> DispatchResult {
+	BizixCore::submit_review(
+		RuntimeOrigin::signed(reviewer),
+		proposal_id,
+		verdict,
+		score,
+		CID_V1.to_vec(),
+	)
+}
+
+#[test]
+fn reviewers_are_assigned_round_robin() {
+	new_test_ext().execute_with(|| {
+		MinReviews::set(2);
+
+		let first = submit_for_voting(1);
+		let second = submit_for_voting(1);
+		// Propunătorul nu își poate recenza propria propunere
+		let third = submit_for_voting(2);
+
+		assert_eq!(BizixCore::reviewers(first), vec![2, 3]);
+		assert_eq!(BizixCore::reviewers(second), vec![4, 5]);
+		assert_eq!(BizixCore::reviewers(third), vec![3, 4]);
+	});
+}
+
+#[test]
+fn reviewers_can_be_assigned_manually() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::assign_reviewers(RuntimeOrigin::signed(2), proposal_id, vec![5]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			BizixCore::assign_reviewers(RuntimeOrigin::root(), proposal_id, vec![1]),
+			Error::<Test>::NotCommitteeMember
+		);
+		assert_ok!(BizixCore::assign_reviewers(RuntimeOrigin::root(), proposal_id, vec![5]));
+		assert_noop!(
+			BizixCore::assign_reviewers(RuntimeOrigin::root(), proposal_id, vec![5]),
+			Error::<Test>::AlreadyReviewer
+		);
+		System::assert_last_event(Event::ReviewerAssigned { proposal_id, reviewer: 5 }.into());
+
+		// Desemnarea automată completează lista până la `MinReviews`
+		MinReviews::set(2);
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			proposal_id,
+			ProposalStatusEnum::InDiscussion
+		));
+		assert_eq!(BizixCore::reviewers(proposal_id), vec![5, 2]);
+	});
+}
+
+#[test]
+fn amending_a_proposal_discards_its_reviews() {
+	new_test_ext().execute_with(|| {
+		MinReviews::set(1);
+		let proposal_id = submit(1);
+		assert_ok!(BizixCore::assign_reviewers(RuntimeOrigin::root(), proposal_id, vec![2]));
+		assert_ok!(review(2, proposal_id, ReviewVerdict::Approve, 8));
+
+		assert_ok!(BizixCore::amend_proposal(
+			RuntimeOrigin::signed(1),
+			proposal_id,
+			Some(CID_V1.to_vec()),
+			None
+		));
+		assert!(BizixCore::reviews(proposal_id, 2).is_none());
+		assert_eq!(BizixCore::reviewers(proposal_id), vec![2]);
+
+		// Votul nu poate fi închis cu recenzia variantei vechi
+		start_voting_on(proposal_id);
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(3), proposal_id, true));
+		assert_noop!(
+			BizixCore::close_voting(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::NotEnoughReviews
+		);
+		assert_ok!(review(2, proposal_id, ReviewVerdict::Approve, 7));
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), proposal_id));
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Approved
+		);
+	});
+}
+
+#[test]
+fn close_voting_requires_minimum_reviews() {
+	new_test_ext().execute_with(|| {
+		MinReviews::set(2);
+		let proposal_id = submit_for_voting(1);
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), proposal_id, true));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(3), proposal_id, true));
+
+		assert_noop!(
+			BizixCore::close_voting(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::NotEnoughReviews
+		);
+
+		assert_noop!(review(4, proposal_id, ReviewVerdict::Approve, 8), Error::<Test>::NotReviewer);
+		assert_noop!(
+			review(2, proposal_id, ReviewVerdict::Approve, MAX_REVIEW_SCORE + 1),
+			Error::<Test>::InvalidScore
+		);
+		assert_ok!(review(2, proposal_id, ReviewVerdict::Approve, 8));
+		assert_noop!(
+			review(2, proposal_id, ReviewVerdict::Reject, 2),
+			Error::<Test>::AlreadyReviewed
+		);
+		assert_ok!(review(3, proposal_id, ReviewVerdict::RequestChanges, 6));
+		System::assert_last_event(
+			Event::ReviewSubmitted {
+				proposal_id,
+				reviewer: 3,
+				verdict: ReviewVerdict::RequestChanges,
+				score: 6,
+			}
+			.into(),
+		);
+
+		let report = BizixCore::reviews(proposal_id, 2).unwrap();
+		assert_eq!(report.report.to_vec(), Cid::parse(CID_V1).unwrap().to_bytes());
+
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), proposal_id));
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Approved
+		);
+	});
+}
+
+#[test]
+fn expired_votes_without_reviews_are_extended() {
+	new_test_ext().execute_with(|| {
+		MinReviews::set(1);
+		let proposal_id = submit_for_voting(1);
+
+		System::set_block_number(11);
+		BizixCore::on_initialize(11);
+
+		let proposal = Proposals::<Test>::get(proposal_id).unwrap();
+		assert_eq!(proposal.status, ProposalStatusEnum::InDiscussion);
+		assert_eq!(proposal.voting_end, Some(21));
+		assert_eq!(VotingDeadlines::<Test>::get(21), vec![proposal_id]);
+		System::assert_last_event(Event::VotingExtended { proposal_id, voting_end: 21 }.into());
+	});
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn votes_expire_after_the_last_extension() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get(), Some(42));
-		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		MinReviews::set(1);
+		let proposal_id = submit_for_voting(1);
+
+		for block in [11, 21, 31] {
+			System::set_block_number(block);
+			BizixCore::on_initialize(block);
+		}
+		assert_eq!(BizixCore::voting_extensions(proposal_id), MAX_VOTING_EXTENSIONS);
+		assert_eq!(Proposals::<Test>::get(proposal_id).unwrap().voting_end, Some(41));
+
+		System::set_block_number(41);
+		BizixCore::on_initialize(41);
+
+		// Respinsă fără penalizare: depozitul se întoarce integral
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Rejected
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert!(Votes::<Test>::get(proposal_id).is_none());
+		assert_eq!(BizixCore::voting_extensions(proposal_id), 0);
+		System::assert_has_event(Event::VotingExpired { proposal_id }.into());
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn required_reviews_are_capped_by_eligible_members() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		// Comitetul are 4 membri, dintre care unul este propunătorul
+		MinReviews::set(5);
+		let proposal_id = submit_for_voting(2);
+		assert_eq!(BizixCore::required_reviews(&2), 3);
+		assert_eq!(BizixCore::reviewers(proposal_id), vec![3, 4, 5]);
+
+		assert_ok!(review(3, proposal_id, ReviewVerdict::Approve, 8));
+		assert_ok!(review(4, proposal_id, ReviewVerdict::Approve, 8));
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			BizixCore::close_voting(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::NotEnoughReviews
 		);
+		assert_ok!(review(5, proposal_id, ReviewVerdict::Approve, 8));
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), proposal_id));
+	});
+}
+
+#[test]
+fn direct_decisions_require_minimum_reviews() {
+	new_test_ext().execute_with(|| {
+		MinReviews::set(1);
+		let proposal_id = submit_for_voting(1);
+
+		assert_noop!(
+			BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::NotEnoughReviews
+		);
+		assert_noop!(
+			BizixCore::reject_proposal(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::NotEnoughReviews
+		);
+
+		assert_ok!(review(2, proposal_id, ReviewVerdict::Reject, 2));
+		assert_ok!(BizixCore::reject_proposal(RuntimeOrigin::root(), proposal_id));
+		assert_eq!(
+			Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Rejected
+		);
+	});
+}
+
+fn attest(auditor: u64, proposal_id: u32, expires_at: u64) -> DispatchResult {
+	BizixCore::attest_audit(
+		RuntimeOrigin::signed(auditor),
+		proposal_id,
+		CID_V1.to_vec(),
+		AuditSeverities { critical: 0, high: 1, medium: 2, low: 5 },
+		expires_at,
+	)
+}
+
+#[test]
+fn audits_expire_and_depend_on_registered_auditors() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::register_auditor(RuntimeOrigin::signed(2), 6),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(BizixCore::register_auditor(RuntimeOrigin::root(), 6));
+		assert_noop!(
+			BizixCore::register_auditor(RuntimeOrigin::root(), 6),
+			Error::<Test>::AlreadyAuditor
+		);
+
+		assert_noop!(attest(7, proposal_id, 20), Error::<Test>::NotAuditor);
+		assert_noop!(attest(6, proposal_id, 1), Error::<Test>::InvalidExpiry);
+		assert!(!BizixCore::is_audited(proposal_id));
+
+		assert_ok!(attest(6, proposal_id, 20));
+		assert!(BizixCore::is_audited(proposal_id));
+		let (auditor, attestation, valid) = BizixCore::audits_of(proposal_id).remove(0);
+		assert_eq!((auditor, attestation.severities.low, valid), (6, 5, true));
+
+		System::set_block_number(20);
+		assert!(!BizixCore::is_audited(proposal_id));
+
+		// O atestare nouă o înlocuiește pe cea expirată
+		assert_ok!(attest(6, proposal_id, 40));
+		assert!(BizixCore::is_audited(proposal_id));
+		assert_ok!(BizixCore::remove_auditor(RuntimeOrigin::root(), 6));
+		assert!(!BizixCore::is_audited(proposal_id));
+		assert_eq!(BizixCore::audits_of(proposal_id).len(), 1);
+	});
+}
+
+#[test]
+fn audits_are_bounded_by_the_auditor_registry() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+		assert_ok!(BizixCore::register_auditor(RuntimeOrigin::root(), 6));
+		assert_ok!(BizixCore::register_auditor(RuntimeOrigin::root(), 7));
+		assert_noop!(
+			BizixCore::register_auditor(RuntimeOrigin::root(), 8),
+			Error::<Test>::TooManyAuditors
+		);
+		assert_ok!(attest(6, proposal_id, 20));
+		assert_ok!(attest(7, proposal_id, 20));
+
+		// Atestarea auditorului scos face loc auditorului nou
+		assert_ok!(BizixCore::remove_auditor(RuntimeOrigin::root(), 6));
+		assert_ok!(BizixCore::register_auditor(RuntimeOrigin::root(), 8));
+		assert_ok!(attest(8, proposal_id, 20));
+		let auditors: Vec<u64> = BizixCore::audits_of(proposal_id)
+			.into_iter()
+			.map(|(auditor, ..)| auditor)
+			.collect();
+		assert_eq!(auditors.len(), 2);
+		assert!(!auditors.contains(&6));
+	});
+}
+
+#[test]
+fn mandatory_audit_blocks_approval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixCore::register_auditor(RuntimeOrigin::root(), 6));
+		assert_ok!(BizixCore::set_audit_required(RuntimeOrigin::root(), true));
+
+		let proposal_id = submit(1);
+		assert_noop!(
+			BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::MissingAudit
+		);
+
+		let voted = start_voting_on(submit_version(1, b"1.1.0"));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(2), voted, true));
+		assert_ok!(BizixCore::vote_on_proposal(RuntimeOrigin::signed(3), voted, true));
+		assert_noop!(
+			BizixCore::close_voting(RuntimeOrigin::root(), voted),
+			Error::<Test>::MissingAudit
+		);
+
+		assert_ok!(attest(6, proposal_id, 20));
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+		assert_ok!(attest(6, voted, 20));
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), voted));
+		assert_eq!(Proposals::<Test>::get(voted).unwrap().status, ProposalStatusEnum::Approved);
+
+		// Propunerile respinse nu au nevoie de audit
+		let rejected = start_voting_on(submit_version(1, b"2.0.0"));
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), rejected));
+		assert_eq!(
+			Proposals::<Test>::get(rejected).unwrap().status,
+			ProposalStatusEnum::Rejected
+		);
+	});
+}
+
+#[test]
+fn illegal_transitions_have_distinct_errors() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::change_proposal_status(
+				RuntimeOrigin::root(),
+				proposal_id,
+				ProposalStatusEnum::Deployed
+			),
+			Error::<Test>::CannotDeploy
+		);
+		assert_noop!(
+			BizixCore::change_proposal_status(
+				RuntimeOrigin::root(),
+				proposal_id,
+				ProposalStatusEnum::Revoked
+			),
+			Error::<Test>::CannotRevoke
+		);
+
+		assert_ok!(BizixCore::reject_proposal(RuntimeOrigin::root(), proposal_id));
+		assert_noop!(
+			BizixCore::withdraw_proposal(RuntimeOrigin::signed(1), proposal_id),
+			Error::<Test>::CannotWithdraw
+		);
+		assert_noop!(
+			BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id),
+			Error::<Test>::CannotApprove
+		);
+	});
+}
+
+#[test]
+fn status_history_is_stamped_with_blocks() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		System::set_block_number(5);
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+		System::set_block_number(9);
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			proposal_id,
+			ProposalStatusEnum::Deprecated
+		));
+
+		assert_eq!(
+			StatusHistory::<Test>::get(proposal_id),
+			vec![
+				(ProposalStatusEnum::New, 1),
+				(ProposalStatusEnum::Approved, 5),
+				(ProposalStatusEnum::Deprecated, 9),
+			]
+		);
+	});
+}
+
+#[test]
+fn proposals_are_indexed_by_status_and_proposer() {
+	new_test_ext().execute_with(|| {
+		let first = submit(1);
+		let second = submit(2);
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+
+		let ids = |(page, _): (Vec<(u32, crate::Proposal<Test>)>, Option<u32>)| {
+			page.into_iter().map(|(id, _)| id).collect::<Vec<_>>()
+		};
+		assert_eq!(
+			ids(BizixCore::proposals_by_status(ProposalStatusEnum::New, None, 10)),
+			vec![second]
+		);
+		assert_eq!(
+			ids(BizixCore::proposals_by_status(ProposalStatusEnum::Approved, None, 10)),
+			vec![first]
+		);
+		assert!(!ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::New, first));
+		assert_eq!(BizixCore::count_by_status(ProposalStatusEnum::New), 1);
+		assert_eq!(ids(BizixCore::proposals_by_proposer(&1, None, 10)), vec![first]);
+		assert_eq!(ids(BizixCore::proposals_by_proposer(&3, None, 10)), Vec::<u32>::new());
+	});
+}
+
+#[test]
+fn proposal_pages_continue_from_cursor() {
+	new_test_ext().execute_with(|| {
+		let submitted: Vec<u32> = (0..5).map(|_| submit(1)).collect();
+
+		let mut seen = Vec::new();
+		let mut cursor = None;
+		loop {
+			let (page, next) = BizixCore::proposals_by_proposer(&1, cursor, 2);
+			assert!(page.len() <= 2);
+			seen.extend(page.into_iter().map(|(id, _)| id));
+			match next {
+				Some(_) => cursor = next,
+				None => break,
+			}
+		}
+		seen.sort();
+		assert_eq!(seen, submitted);
+
+		// Ultima pagină nu are cursor
+		let (page, next) = BizixCore::proposals_by_status(ProposalStatusEnum::New, None, 5);
+		assert_eq!((page.len(), next), (5, None));
+	});
+}
+
+#[test]
+fn status_query_call_is_deprecated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BizixCore::get_proposals_by_status(RuntimeOrigin::signed(1), ProposalStatusEnum::New),
+			Error::<Test>::Deprecated
+		);
+	});
+}
+
+#[test]
+fn approved_versions_are_listed_in_catalog() {
+	new_test_ext().execute_with(|| {
+		let first = submit_version(1, b"1.0.0");
+		let second = submit_version(1, b"1.1.0");
+
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), second));
+
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		assert_eq!(application.publisher, 1);
+		assert_eq!(application.releases.len(), 2);
+		assert_eq!(application.latest_release().unwrap().proposal_id, second);
+		assert_eq!(
+			Proposals::<Test>::get(first).unwrap().status,
+			ProposalStatusEnum::Superseded
+		);
+		assert_eq!(BizixCore::application_count(), 1);
+
+		// Revocarea ultimei versiuni o scoate din catalog
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			second,
+			ProposalStatusEnum::Revoked
+		));
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		assert_eq!(application.latest_release().unwrap().proposal_id, first);
+		// Versiunea anterioară redevine recomandată
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatusEnum::Approved);
+
+		// Fără nicio versiune, aplicația dispare din catalog
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			first,
+			ProposalStatusEnum::Revoked
+		));
+		assert!(Applications::<Test>::get(app_name()).is_none());
+		assert_eq!(BizixCore::application_count(), 0);
+	});
+}
+
+#[test]
+fn revoking_the_latest_version_restores_a_deployed_predecessor() {
+	new_test_ext().execute_with(|| {
+		let first = submit_version(1, b"1.0.0");
+		let second = submit_version(1, b"1.1.0");
+		let third = submit_version(1, b"1.2.0");
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+		assert_ok!(BizixCore::note_deployed(first));
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), second));
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), third));
+
+		// Revocarea unei versiuni intermediare nu schimbă versiunea recomandată
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			second,
+			ProposalStatusEnum::Revoked
+		));
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatusEnum::Superseded);
+
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			third,
+			ProposalStatusEnum::Revoked
+		));
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatusEnum::Deployed);
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::Deployed, first));
+	});
+}
+
+/// Completează istoricul propunerii până la `len` intrări.
+fn pad_status_history(proposal_id: u32, len: usize) {
+	StatusHistory::<Test>::mutate(proposal_id, |history| {
+		while history.len() < len {
+			history.try_push((ProposalStatusEnum::Approved, 1)).unwrap();
+		}
+	});
+}
+
+#[test]
+fn failed_supersede_leaves_the_status_index_intact() {
+	new_test_ext().execute_with(|| {
+		let first = submit_version(1, b"1.0.0");
+		let second = submit_version(1, b"1.1.0");
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+		pad_status_history(first, MaxStatusChanges::get() as usize);
+
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), second));
+
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatusEnum::Approved);
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::Approved, first));
+		assert!(!ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::Superseded, first));
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		assert_eq!(application.latest_release().unwrap().proposal_id, second);
+	});
+}
+
+#[test]
+fn restoring_needs_room_for_a_full_cycle() {
+	new_test_ext().execute_with(|| {
+		let first = submit_version(1, b"1.0.0");
+		let second = submit_version(1, b"1.1.0");
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+		// După înlocuire rămân mai puțin de `STATUS_CYCLE_LENGTH` intrări libere
+		pad_status_history(first, (MaxStatusChanges::get() - STATUS_CYCLE_LENGTH) as usize);
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), second));
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatusEnum::Superseded);
+
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			second,
+			ProposalStatusEnum::Revoked
+		));
+		assert_eq!(Proposals::<Test>::get(first).unwrap().status, ProposalStatusEnum::Superseded);
+
+		// Revocarea are în continuare loc în istoric
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			first,
+			ProposalStatusEnum::Revoked
+		));
+		assert_eq!(StatusHistory::<Test>::get(first).len(), MaxStatusChanges::get() as usize - 3);
+	});
+}
+
+#[test]
+fn semver_parsing_and_precedence() {
+	let ordered: [&[u8]; 9] = [
+		b"1.0.0-alpha",
+		b"1.0.0-alpha.1",
+		b"1.0.0-alpha.beta",
+		b"1.0.0-beta",
+		b"1.0.0-beta.2",
+		b"1.0.0-beta.11",
+		b"1.0.0-rc.1",
+		b"1.0.0",
+		b"2.0.0",
+	];
+	for pair in ordered.windows(2) {
+		assert!(SemVer::parse(pair[0]).unwrap() < SemVer::parse(pair[1]).unwrap());
+	}
+
+	assert_eq!(
+		SemVer::parse(b"1.2.3-rc.1+build.5"),
+		Some(SemVer {
+			major: 1,
+			minor: 2,
+			patch: 3,
+			pre: vec![Identifier::AlphaNumeric(b"rc".to_vec()), Identifier::Numeric(1)],
+		})
+	);
+	// Metadatele de build nu contează la ordonare
+	assert_eq!(SemVer::parse(b"1.0.0+linux"), SemVer::parse(b"1.0.0"));
+
+	for invalid in [&b"latest"[..], b"v2!!", b"1.0", b"1.0.0.0", b"01.0.0", b"1.0.0-", b"1.0.0-01", b"1.0.0+"] {
+		assert_eq!(SemVer::parse(invalid), None);
+	}
+}
+
+#[test]
+fn malformed_versions_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			BizixCore::submit_proposal(
+				RuntimeOrigin::signed(1),
+				b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+				app_name(),
+				BoundedVec::truncate_from(b"latest".to_vec()),
+				7,
+			),
+			Error::<Test>::InvalidVersion
+		);
+	});
+}
+
+#[test]
+fn new_versions_must_be_higher_than_latest_release() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit_version(1, b"2.0.0");
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+
+		assert_noop!(
+			BizixCore::submit_proposal(
+				RuntimeOrigin::signed(1),
+				b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+				app_name(),
+				BoundedVec::truncate_from(b"2.0.0-rc.1".to_vec()),
+				7,
+			),
+			Error::<Test>::VersionNotHigher
+		);
+		assert_noop!(
+			BizixCore::submit_proposal(
+				RuntimeOrigin::signed(1),
+				b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+				app_name(),
+				BoundedVec::truncate_from(b"2.0.0+rebuild".to_vec()),
+				7,
+			),
+			Error::<Test>::VersionNotHigher
+		);
+		submit_version(1, b"2.0.1-beta");
+	});
+}
+
+#[test]
+fn catalog_keeps_releases_sorted() {
+	new_test_ext().execute_with(|| {
+		let newer = submit_version(1, b"1.1.0");
+		let older = submit_version(1, b"1.1.0-rc.1");
+
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), newer));
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), older));
+
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		let ids: Vec<u32> = application.releases.iter().map(|r| r.proposal_id).collect();
+		assert_eq!(ids, vec![older, newer]);
+		assert_eq!(application.latest_release().unwrap().proposal_id, newer);
+		// Versiunea mai veche aprobată ulterior nu o înlocuiește pe cea curentă
+		assert_eq!(Proposals::<Test>::get(newer).unwrap().status, ProposalStatusEnum::Approved);
+	});
+}
+
+#[test]
+fn catalog_drops_oldest_release_when_full() {
+	new_test_ext().execute_with(|| {
+		let ids: Vec<u32> = [&b"1.0.0"[..], b"1.1.0", b"1.2.0", b"2.0.0"]
+			.iter()
+			.map(|version| {
+				let proposal_id = submit_version(1, version);
+				assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+				proposal_id
+			})
+			.collect();
+
+		// `MaxReleases` este 3 în mock
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		let kept: Vec<u32> = application.releases.iter().map(|r| r.proposal_id).collect();
+		assert_eq!(kept, ids[1..].to_vec());
+		assert_eq!(application.latest_release().unwrap().proposal_id, ids[3]);
+		System::assert_has_event(
+			Event::ApplicationReleaseRemoved { name: app_name(), proposal_id: ids[0] }.into(),
+		);
+	});
+}
+
+const CID: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+
+#[test]
+fn block_size_is_parsed_from_block_stat() {
+	let body = br#"{"Key":"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG","Size": 48213}"#;
+	assert_eq!(ipfs::parse_block_size(body), Some(48_213));
+	assert_eq!(ipfs::parse_block_size(br#"{"Message":"not found"}"#), None);
+}
+
+#[test]
+fn only_attesters_submit_content_checks() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+
+		assert_noop!(
+			BizixCore::submit_content_check(RuntimeOrigin::signed(1), proposal_id, true, None, false),
+			Error::<Test>::NotAttester
+		);
+		assert_ok!(BizixCore::submit_content_check(
+			RuntimeOrigin::signed(5),
+			proposal_id,
+			true,
+			Some(1_024),
+			false
+		));
+		let check = ContentChecks::<Test>::get(proposal_id).unwrap();
+		assert_eq!((check.available, check.size, check.attester), (true, Some(1_024), 5));
+	});
+}
+
+#[test]
+fn unavailable_content_cannot_go_to_vote() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+		assert_ok!(BizixCore::submit_content_check(
+			RuntimeOrigin::signed(5),
+			proposal_id,
+			false,
+			None,
+			false
+		));
+		System::assert_has_event(Event::ContentUnavailable { proposal_id }.into());
+
+		assert_noop!(
+			BizixCore::change_proposal_status(
+				RuntimeOrigin::root(),
+				proposal_id,
+				ProposalStatusEnum::InDiscussion
+			),
+			Error::<Test>::ContentUnavailable
+		);
+
+		// O verificare ulterioară reușită deblochează votul
+		assert_ok!(BizixCore::submit_content_check(
+			RuntimeOrigin::signed(5),
+			proposal_id,
+			true,
+			Some(1_024),
+			false
+		));
+		assert_ok!(BizixCore::change_proposal_status(
+			RuntimeOrigin::root(),
+			proposal_id,
+			ProposalStatusEnum::InDiscussion
+		));
+	});
+}
+
+#[test]
+fn offchain_worker_checks_and_pins_content() {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	{
+		let mut state = offchain_state.write();
+		state.expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: format!("http://127.0.0.1:5001/api/v0/block/stat?arg={}", CID),
+			response: Some(br#"{"Size":48213}"#.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		state.expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: format!("http://127.0.0.1:5001/api/v0/block/stat?arg={}", CID),
+			response: Some(br#"{"Size":48213}"#.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+		state.expect_request(PendingRequest {
+			method: "POST".into(),
+			uri: format!("http://127.0.0.1:5001/api/v0/pin/add?arg={}", CID),
+			response: Some(br#"{"Pins":["QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"]}"#.to_vec()),
+			sent: true,
+			..Default::default()
+		});
+	}
+
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys(vec![5u64]);
+		let proposal_id = submit(1);
+
+		BizixCore::offchain_worker(1);
+		let tx = Extrinsic::decode(&mut &*pool_state.write().transactions.pop().unwrap()).unwrap();
+		assert_eq!(tx.signature, Some((0, ())));
+		assert_eq!(
+			tx.call,
+			RuntimeCall::BizixCore(crate::Call::submit_content_check {
+				proposal_id,
+				available: true,
+				size: Some(48_213),
+				pinned: false,
+			})
+		);
+
+		// Verificarea nu se repetă la blocul următor
+		BizixCore::offchain_worker(2);
+		assert!(pool_state.read().transactions.is_empty());
+
+		// După aprobare, conținutul este fixat pe nodul IPFS
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+		BizixCore::offchain_worker(1 + crate::CONTENT_CHECK_RETRY_BLOCKS as u64);
+		let tx = Extrinsic::decode(&mut &*pool_state.write().transactions.pop().unwrap()).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::BizixCore(crate::Call::submit_content_check {
+				proposal_id,
+				available: true,
+				size: Some(48_213),
+				pinned: true,
+			})
+		);
+	});
+}
+
+#[test]
+fn offchain_worker_skips_proposals_that_need_no_check() {
+	let (offchain, _) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	// Nicio cerere HTTP așteptată: orice interogare a nodului IPFS ar eșua testul
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys(vec![5u64]);
+		let proposal_id = submit(1);
+		assert_ok!(BizixCore::reject_proposal(RuntimeOrigin::root(), proposal_id));
+
+		BizixCore::offchain_worker(1);
+		assert!(pool_state.read().transactions.is_empty());
+	});
+}
+
+const CID_V1: &[u8] = b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+// CIDv1 cu codec-ul raw, pe care `object/stat` nu îl acceptă
+const CID_RAW: &str = "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e";
+
+#[test]
+fn offchain_worker_checks_raw_content() {
+	let (offchain, offchain_state) = TestOffchainExt::new();
+	let (pool, pool_state) = TestTransactionPoolExt::new();
+	let mut ext = new_test_ext();
+	ext.register_extension(OffchainDbExt::new(offchain.clone()));
+	ext.register_extension(OffchainWorkerExt::new(offchain));
+	ext.register_extension(TransactionPoolExt::new(pool));
+
+	offchain_state.write().expect_request(PendingRequest {
+		method: "POST".into(),
+		uri: format!("http://127.0.0.1:5001/api/v0/block/stat?arg={}", CID_RAW),
+		response: Some(format!(r#"{{"Key":"{}","Size":11}}"#, CID_RAW).into_bytes()),
+		sent: true,
+		..Default::default()
+	});
+
+	ext.execute_with(|| {
+		UintAuthorityId::set_all_keys(vec![5u64]);
+		let proposal_id = BizixCore::current_proposal_id();
+		assert_ok!(BizixCore::submit_proposal(
+			RuntimeOrigin::signed(1),
+			CID_RAW.as_bytes().to_vec(),
+			app_name(),
+			BoundedVec::truncate_from(b"1.0.0".to_vec()),
+			7,
+		));
+
+		BizixCore::offchain_worker(1);
+		let tx = Extrinsic::decode(&mut &*pool_state.write().transactions.pop().unwrap()).unwrap();
+		assert_eq!(
+			tx.call,
+			RuntimeCall::BizixCore(crate::Call::submit_content_check {
+				proposal_id,
+				available: true,
+				size: Some(11),
+				pinned: false,
+			})
+		);
+	});
+}
+
+#[test]
+fn cids_round_trip_through_binary_form() {
+	let v0 = Cid::parse(CID.as_bytes()).unwrap();
+	assert_eq!((v0.version, v0.codec, v0.hash), (0, cid::CODEC_DAG_PB, cid::HASH_SHA2_256));
+	assert_eq!(v0.to_bytes().len(), 34);
+	assert_eq!(cid::render(&v0.to_bytes()).unwrap(), CID.as_bytes());
+
+	let v1 = Cid::parse(CID_V1).unwrap();
+	assert_eq!((v1.version, v1.codec, v1.hash), (1, cid::CODEC_DAG_PB, cid::HASH_SHA2_256));
+	assert_eq!(cid::render(&v1.to_bytes()).unwrap(), CID_V1);
+
+	// Aceleași octeți în base32 cu majuscule și în base16
+	assert_eq!(Cid::parse(&CID_V1.to_ascii_uppercase()).unwrap(), v1);
+	let hex: String = v1.to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+	assert_eq!(Cid::parse(format!("f{}", hex).as_bytes()).unwrap(), v1);
+
+	let blake2b =
+		Cid { version: 1, codec: cid::CODEC_RAW, hash: cid::HASH_BLAKE2B_256, digest: vec![7; 32] };
+	assert_eq!(Cid::parse(&blake2b.to_string_bytes()).unwrap(), blake2b);
+}
+
+#[test]
+fn cids_outside_the_allowlist_are_rejected() {
+	assert_eq!(Cid::parse(b""), Err(CidError::InvalidEncoding));
+	assert_eq!(Cid::parse(b"https://ipfs.io/ipfs/x"), Err(CidError::UnsupportedMultibase));
+	assert_eq!(Cid::parse(b"bafy0OI"), Err(CidError::InvalidEncoding));
+	assert_eq!(
+		Cid::parse(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0"),
+		Err(CidError::InvalidEncoding)
+	);
+	// Literele mari și mici nu se pot amesteca în base32
+	assert_eq!(
+		Cid::parse(b"bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzDI"),
+		Err(CidError::InvalidEncoding)
+	);
+
+	let sha2_512 = Cid { version: 1, codec: cid::CODEC_RAW, hash: 0x13, digest: vec![1; 32] };
+	assert_eq!(Cid::parse(&sha2_512.to_string_bytes()), Err(CidError::UnsupportedHash));
+	let dag_json = Cid { version: 1, codec: 0x0129, hash: cid::HASH_SHA2_256, digest: vec![1; 32] };
+	assert_eq!(Cid::parse(&dag_json.to_string_bytes()), Err(CidError::UnsupportedCodec));
+	let short =
+		Cid { version: 1, codec: cid::CODEC_RAW, hash: cid::HASH_SHA2_256, digest: vec![1; 20] };
+	assert_eq!(Cid::parse(&short.to_string_bytes()), Err(CidError::InvalidDigestLength));
+
+	// Un CIDv0 scris cu prefix multibase nu este canonic
+	let v0 = Cid::parse(CID.as_bytes()).unwrap();
+	let hex: String = v0.to_bytes().iter().map(|b| format!("{:02x}", b)).collect();
+	assert_eq!(Cid::parse(format!("f{}", hex).as_bytes()), Err(CidError::UnsupportedVersion));
+}
+
+#[test]
+fn proposals_store_cids_in_binary_form() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = submit(1);
+		let stored = Proposals::<Test>::get(proposal_id).unwrap().ipfs_address;
+		assert_eq!(stored, Cid::parse(CID.as_bytes()).unwrap().to_bytes());
+
+		for (address, error) in [
+			(&b""[..], Error::<Test>::InvalidCid),
+			(
+				&b"https://ipfs.io/ipfs/QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"[..],
+				Error::<Test>::InvalidCid,
+			),
+			(&b"bafkreiunsupported"[..], Error::<Test>::InvalidCid),
+			(
+				&b"bafkrgiabaeaqcaibaeaqcaibaeaqcaibaeaqcaibaeaqcaibaeaqcaibae"[..],
+				Error::<Test>::UnsupportedCid,
+			),
+		] {
+			assert_noop!(
+				BizixCore::submit_proposal(
+					RuntimeOrigin::signed(1),
+					address.to_vec(),
+					app_name(),
+					BoundedVec::truncate_from(b"2.0.0".to_vec()),
+					7,
+				),
+				error
+			);
+		}
+	});
+}
+
+#[test]
+fn migration_to_v1_reencodes_proposals() {
+	use crate::migrations::v1::{v0, HeldProposals, HoldReason, MigrateToV1};
+
+	new_test_ext().execute_with(|| {
+		MinReviews::set(1);
+		StorageVersion::new(0).put::<BizixCore>();
+		v0::ProposalName::<Test>::put(b"wordpress".to_vec());
+		v0::ProposalStatus::<Test>::put(ProposalStatusEnum::New);
+
+		let old = |status, ipfs_address: &[u8]| v0::OldProposal {
+			proposer: 1u64,
+			ipfs_address: ipfs_address.to_vec(),
+			name: b"wordpress".to_vec(),
+			version: b"1.0.0".to_vec(),
+			template_id: 7u32,
+			status,
+		};
+		v0::Proposals::<Test>::insert(0, old(ProposalStatusEnum::Approved, CID.as_bytes()));
+		v0::Proposals::<Test>::insert(1, old(ProposalStatusEnum::InDiscussion, CID.as_bytes()));
+		v0::Proposals::<Test>::insert(2, old(ProposalStatusEnum::New, b"not a cid"));
+		// Numele depășește limita din mock
+		v0::Proposals::<Test>::insert(
+			3,
+			v0::OldProposal { name: vec![b'a'; 33], ..old(ProposalStatusEnum::New, CID.as_bytes()) },
+		);
+		// O versiune aprobată mai veche, cu id mai mare
+		v0::Proposals::<Test>::insert(
+			4,
+			v0::OldProposal {
+				version: b"0.9.0".to_vec(),
+				..old(ProposalStatusEnum::Approved, CID.as_bytes())
+			},
+		);
+		// În versiunea 0, `Votes` păstra doar conturile care au votat "pentru"
+		for (proposal_id, voters) in [(0u32, vec![2u64]), (1, vec![2, 3])] {
+			frame_support::storage::unhashed::put(
+				&Votes::<Test>::hashed_key_for(proposal_id),
+				&voters,
+			);
+		}
+
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<BizixCore>(), 1);
+		assert!(!v0::ProposalName::<Test>::exists());
+		assert!(!v0::ProposalStatus::<Test>::exists());
+
+		let approved = Proposals::<Test>::get(0).unwrap();
+		assert_eq!(approved.ipfs_address, Cid::parse(CID.as_bytes()).unwrap().to_bytes());
+		assert_eq!((approved.status, approved.deposit), (ProposalStatusEnum::Approved, 0));
+		assert!(Votes::<Test>::get(0).is_none());
+
+		let discussed = Proposals::<Test>::get(1).unwrap();
+		assert_eq!(discussed.voting_end, Some(11));
+		let record = Votes::<Test>::get(1).unwrap();
+		assert_eq!((record.ayes, record.nays), (2, 0));
+		assert_eq!(record.voters, vec![(2, true), (3, true)]);
+
+		// Istoricul începe cu statusul de la migrare
+		assert_eq!(
+			StatusHistory::<Test>::get(0).into_inner(),
+			vec![(ProposalStatusEnum::Approved, 1)]
+		);
+
+		// Propunerile care nu se pot converti sunt păstrate neschimbate, nu șterse
+		assert!(Proposals::<Test>::get(2).is_none());
+		let (held, reason) = HeldProposals::<Test>::get(2).unwrap();
+		assert_eq!((held.ipfs_address, reason), (b"not a cid".to_vec(), HoldReason::InvalidCid));
+		assert!(Proposals::<Test>::get(3).is_none());
+		assert_eq!(HeldProposals::<Test>::get(3).unwrap().1, HoldReason::Bounds);
+		assert!(StatusHistory::<Test>::get(3).is_empty());
+
+		// Versiunile aprobate intră în catalog în ordinea versiunilor, nu a id-urilor
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		let releases: Vec<u32> = application.releases.iter().map(|r| r.proposal_id).collect();
+		assert_eq!(releases, vec![4, 0]);
+		assert_eq!(application.latest_release().unwrap().proposal_id, 0);
+		assert_eq!(Proposals::<Test>::get(4).unwrap().status, ProposalStatusEnum::Superseded);
+		assert_eq!(
+			StatusHistory::<Test>::get(4).into_inner(),
+			vec![(ProposalStatusEnum::Approved, 1), (ProposalStatusEnum::Superseded, 1)]
+		);
+
+		// Votul vechi are recenzenți, deci poate fi închis normal
+		assert_eq!(BizixCore::reviewers(1), vec![2]);
+		assert_noop!(
+			BizixCore::close_voting(RuntimeOrigin::root(), 1),
+			Error::<Test>::NotEnoughReviews
+		);
+		assert_ok!(review(2, 1, ReviewVerdict::Approve, 8));
+		assert_ok!(BizixCore::close_voting(RuntimeOrigin::root(), 1));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().status, ProposalStatusEnum::Approved);
+	});
+}
+
+#[test]
+fn held_proposals_can_be_recovered_by_root() {
+	use crate::migrations::v1::{v0, HeldProposals, MigrateToV1};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<BizixCore>();
+		let old = |status, ipfs_address: &[u8]| v0::OldProposal {
+			proposer: 1u64,
+			ipfs_address: ipfs_address.to_vec(),
+			name: b"wordpress".to_vec(),
+			version: b"1.0.0".to_vec(),
+			template_id: 7u32,
+			status,
+		};
+		v0::Proposals::<Test>::insert(0, old(ProposalStatusEnum::InDiscussion, b"not a cid"));
+		v0::Proposals::<Test>::insert(
+			1,
+			v0::OldProposal {
+				name: vec![b'a'; 33],
+				..old(ProposalStatusEnum::Approved, CID.as_bytes())
+			},
+		);
+		MigrateToV1::<Test>::on_runtime_upgrade();
+
+		let recover = |origin, proposal_id, ipfs_address: &[u8], version: &[u8]| {
+			BizixCore::recover_held_proposal(
+				origin,
+				proposal_id,
+				ipfs_address.to_vec(),
+				app_name(),
+				BoundedVec::truncate_from(version.to_vec()),
+			)
+		};
+		assert_noop!(
+			recover(RuntimeOrigin::signed(1), 0, CID.as_bytes(), b"1.0.0"),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			recover(RuntimeOrigin::root(), 5, CID.as_bytes(), b"1.0.0"),
+			Error::<Test>::NotHeld
+		);
+		assert_noop!(
+			recover(RuntimeOrigin::root(), 0, b"not a cid", b"1.0.0"),
+			Error::<Test>::InvalidCid
+		);
+		assert_noop!(
+			recover(RuntimeOrigin::root(), 0, CID.as_bytes(), b"latest"),
+			Error::<Test>::InvalidVersion
+		);
+
+		// Votul nu se reia automat
+		assert_ok!(recover(RuntimeOrigin::root(), 0, CID.as_bytes(), b"1.0.0"));
+		let proposal = Proposals::<Test>::get(0).unwrap();
+		assert_eq!((proposal.proposer, proposal.template_id), (1, 7));
+		assert_eq!((proposal.status, proposal.deposit), (ProposalStatusEnum::New, 0));
+		assert!(HeldProposals::<Test>::get(0).is_none());
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::New, 0));
+		assert!(ProposalsByProposer::<Test>::contains_key(1, 0));
+		assert_eq!(StatusHistory::<Test>::get(0).into_inner(), vec![(ProposalStatusEnum::New, 1)]);
+		System::assert_last_event(
+			Event::HeldProposalRecovered { proposal_id: 0, status: ProposalStatusEnum::New }.into(),
+		);
+		assert_noop!(
+			recover(RuntimeOrigin::root(), 0, CID.as_bytes(), b"1.0.0"),
+			Error::<Test>::NotHeld
+		);
+
+		// O propunere aprobată intră în catalog
+		assert_ok!(recover(RuntimeOrigin::root(), 1, CID.as_bytes(), b"1.1.0"));
+		assert_eq!(Proposals::<Test>::get(1).unwrap().status, ProposalStatusEnum::Approved);
+		let application = Applications::<Test>::get(app_name()).unwrap();
+		assert_eq!(application.latest_release().unwrap().proposal_id, 1);
+	});
+}
+
+#[test]
+fn migration_to_v2_builds_proposal_indices() {
+	use crate::migrations::v2::MigrateToV2;
+
+	new_test_ext().execute_with(|| {
+		let first = submit(1);
+		let second = submit(2);
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+		// Starea dinaintea indexurilor
+		let _ = ProposalsByStatus::<Test>::clear(u32::MAX, None);
+		let _ = ProposalsByProposer::<Test>::clear(u32::MAX, None);
+		StorageVersion::new(1).put::<BizixCore>();
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<BizixCore>(), 2);
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::Approved, first));
+		assert!(ProposalsByStatus::<Test>::contains_key(ProposalStatusEnum::New, second));
+		assert!(ProposalsByProposer::<Test>::contains_key(1, first));
+		assert!(ProposalsByProposer::<Test>::contains_key(2, second));
+	});
+}
+
+#[test]
+fn migration_to_v3_counts_applications() {
+	use crate::migrations::v3::MigrateToV3;
+
+	new_test_ext().execute_with(|| {
+		let first = submit_version(1, b"1.0.0");
+		assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), first));
+		ApplicationCount::<Test>::kill();
+		StorageVersion::new(2).put::<BizixCore>();
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<BizixCore>(), 3);
+		assert_eq!(BizixCore::application_count(), 1);
 	});
 }
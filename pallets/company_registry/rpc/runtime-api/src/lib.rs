@@ -5,25 +5,72 @@ use sp_std::vec::Vec;
 use scale_info::TypeInfo;
 
 sp_api::decl_runtime_apis! {
-    pub trait CompanyRegistryApi<AccountId, Balance> where
+    // Versiunea 2: CUI pe 64 de biți, județul și registratorul în `Company`, ofertele de
+    // transfer și istoricul firmei
+    #[api_version(2)]
+    pub trait CompanyRegistryApi<AccountId, Balance, BlockNumber> where
         AccountId: Codec + TypeInfo,
         Balance: Codec + TypeInfo,
+        BlockNumber: Codec + TypeInfo,
     {
-        fn get_company_data(cui: u16, caller: AccountId) -> Option<Company<AccountId>>;
+        fn get_company_data(cui: u64, caller: AccountId) -> Option<Company<AccountId>>;
         fn get_query_fee() -> Balance;
-        fn has_paid_for_company_data(caller: AccountId, cui: u16) -> bool;
-        fn get_company_data_if_paid(caller: AccountId, cui: u16) -> Option<Company<AccountId>>;
+        fn has_paid_for_company_data(caller: AccountId, cui: u64) -> bool;
+        fn get_company_data_if_paid(caller: AccountId, cui: u64) -> Option<Company<AccountId>>;
+        // Ofertele de transfer neexpirate primite de cont
+        fn pending_transfers(account: AccountId) -> Vec<TransferOffer<AccountId, BlockNumber>>;
+        // Reviziile păstrate ale firmei, de la cea mai veche
+        fn company_history(cui: u64) -> Vec<CompanyRevision<AccountId, BlockNumber>>;
     }
 }
 
 // definim structura Company aici pentru a fi compatibilă cu API-ul runtime
 #[derive(codec::Encode, codec::Decode, TypeInfo)]
 pub struct Company<AccountId> {
-    pub cui: u16,
+    pub cui: u64,
     pub denumire: Vec<u8>,
     pub cod_inmatriculare: Vec<u8>,
     pub euid: Vec<u8>,
     pub stare_firma: Vec<u8>,
     pub adresa_completa: Vec<u8>,
     pub owner: Option<AccountId>,
-}
\ No newline at end of file
+    // Indicativul județului, gol pentru firmele fără județ
+    pub judet: Option<Vec<u8>>,
+    pub attested_by: Option<AccountId>,
+}
+
+#[derive(codec::Encode, codec::Decode, TypeInfo)]
+pub struct TransferOffer<AccountId, BlockNumber> {
+    pub cui: u64,
+    pub from: AccountId,
+    pub to: AccountId,
+    pub expires_at: BlockNumber,
+}
+
+#[derive(codec::Encode, codec::Decode, TypeInfo)]
+pub enum CompanyField {
+    Denumire,
+    CodInmatriculare,
+    Euid,
+    StareFirma,
+    AdresaCompleta,
+    Judet,
+    Owner,
+}
+
+#[derive(codec::Encode, codec::Decode, TypeInfo)]
+pub struct FieldChange {
+    pub field: CompanyField,
+    // Valorile sunt textul câmpului; pentru `Owner`, contul codificat SCALE.
+    // Lipsește la crearea firmei și la primul proprietar
+    pub old: Option<Vec<u8>>,
+    pub new: Vec<u8>,
+}
+
+#[derive(codec::Encode, codec::Decode, TypeInfo)]
+pub struct CompanyRevision<AccountId, BlockNumber> {
+    // Lipsește la modificările făcute de `RegistrarOrigin`
+    pub author: Option<AccountId>,
+    pub block: BlockNumber,
+    pub changes: Vec<FieldChange>,
+}
@@ -1,4 +1,5 @@
 pub use pallet_company_registry_rpc_runtime_api::CompanyRegistryApi as CompanyRegistryRuntimeApi;
+use pallet_company_registry_rpc_runtime_api::CompanyField;
 use codec::Codec;
 use jsonrpsee::{
     core::RpcResult,
@@ -15,15 +16,21 @@ use serde::{Serialize, Deserialize};
 use sp_runtime::traits::MaybeDisplay;
 
 #[rpc(client, server)]
-pub trait CompanyRegistryApi<BlockHash, AccountId, Balance> {
+pub trait CompanyRegistryApi<BlockHash, AccountId, Balance, BlockNumber> {
     #[method(name = "companyRegistry_getCompanyData")]
-    fn get_company_data(&self, cui: u16, caller: AccountId, at: Option<BlockHash>) -> RpcResult<Option<CompanyData>>;
+    fn get_company_data(&self, cui: u64, caller: AccountId, at: Option<BlockHash>) -> RpcResult<Option<CompanyData>>;
 
     #[method(name = "companyRegistry_getQueryFee")]
     fn get_query_fee(&self, at: Option<BlockHash>) -> RpcResult<Balance>;
 
     #[method(name = "companyRegistry_getCompanyDataIfPaid")]
-    fn get_company_data_if_paid(&self, cui: u16, caller: AccountId, at: Option<BlockHash>) -> RpcResult<Option<CompanyData>>;
+    fn get_company_data_if_paid(&self, cui: u64, caller: AccountId, at: Option<BlockHash>) -> RpcResult<Option<CompanyData>>;
+
+    #[method(name = "companyRegistry_getPendingTransfers")]
+    fn get_pending_transfers(&self, account: AccountId, at: Option<BlockHash>) -> RpcResult<Vec<TransferOfferData>>;
+
+    #[method(name = "companyRegistry_getCompanyHistory")]
+    fn get_company_history(&self, cui: u64, at: Option<BlockHash>) -> RpcResult<Vec<RevisionData>>;
 }
 
 pub struct CompanyRegistry<C, Block> {
@@ -39,28 +46,79 @@ impl<C, Block> CompanyRegistry<C, Block> {
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct CompanyData {
-    pub cui: u16,
+    pub cui: u64,
     pub denumire: String,
     pub cod_inmatriculare: String,
     pub euid: String,
     pub stare_firma: String,
     pub adresa_completa: String,
     pub owner: Option<String>, // Vom folosi String pentru a reprezenta AccountId
+    pub judet: Option<String>,
+    // Registratorul care a confirmat ultima dată datele
+    pub attested_by: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TransferOfferData {
+    pub cui: u64,
+    pub from: String,
+    pub to: String,
+    pub expires_at: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct FieldChangeData {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: String,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct RevisionData {
+    pub block: u64,
+    /// Lipsește la modificările făcute de `RegistrarOrigin`.
+    pub author: Option<String>,
+    pub changes: Vec<FieldChangeData>,
+}
+
+// Numele câmpurilor, așa cum apar în `CompanyData`
+fn field_name(field: &CompanyField) -> &'static str {
+    match field {
+        CompanyField::Denumire => "denumire",
+        CompanyField::CodInmatriculare => "cod_inmatriculare",
+        CompanyField::Euid => "euid",
+        CompanyField::StareFirma => "stare_firma",
+        CompanyField::AdresaCompleta => "adresa_completa",
+        CompanyField::Judet => "judet",
+        CompanyField::Owner => "owner",
+    }
+}
+
+// Proprietarul vine ca cont codificat SCALE, celelalte câmpuri ca text
+fn field_value<AccountId: Codec + sp_std::fmt::Display>(field: &CompanyField, value: &[u8]) -> String {
+    match field {
+        CompanyField::Owner => AccountId::decode(&mut &value[..])
+            .map(|account| account.to_string())
+            .unwrap_or_else(|_| String::from_utf8_lossy(value).into_owned()),
+        _ => String::from_utf8_lossy(value).into_owned(),
+    }
 }
 
 pub trait AccountIdSerialize: Codec + Clone + MaybeDisplay {}
 impl<T: Codec + Clone + MaybeDisplay> AccountIdSerialize for T {}
 
-impl<C, Block, AccountId, Balance> CompanyRegistryApiServer<<Block as BlockT>::Hash, AccountId, Balance>
+impl<C, Block, AccountId, Balance, BlockNumber>
+    CompanyRegistryApiServer<<Block as BlockT>::Hash, AccountId, Balance, BlockNumber>
     for CompanyRegistry<C, Block>
 where
     Block: BlockT,
     C: Send + Sync + 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block>,
-    C::Api: CompanyRegistryRuntimeApi<Block, AccountId, Balance>,
+    C::Api: CompanyRegistryRuntimeApi<Block, AccountId, Balance, BlockNumber>,
     AccountId: Codec + Clone + sp_std::fmt::Display + scale_info::TypeInfo,
     Balance: Codec + scale_info::TypeInfo,
+    BlockNumber: Codec + scale_info::TypeInfo + Into<u64>,
 {
-     fn get_company_data(&self, cui: u16, caller: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<CompanyData>> {
+     fn get_company_data(&self, cui: u64, caller: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<CompanyData>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
@@ -80,10 +138,12 @@ where
             stare_firma: String::from_utf8_lossy(&c.stare_firma).into_owned(),
             adresa_completa: String::from_utf8_lossy(&c.adresa_completa).into_owned(),
             owner: c.owner.map(|a| a.to_string()),
+            judet: c.judet.map(|j| String::from_utf8_lossy(&j).into_owned()),
+            attested_by: c.attested_by.map(|a| a.to_string()),
         }))
     }
 
-    fn get_company_data_if_paid(&self, cui: u16, caller: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<CompanyData>> {
+    fn get_company_data_if_paid(&self, cui: u64, caller: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Option<CompanyData>> {
         let api = self.client.runtime_api();
         let at = at.unwrap_or_else(|| self.client.info().best_hash);
 
@@ -117,6 +177,8 @@ where
             stare_firma: String::from_utf8_lossy(&c.stare_firma).into_owned(),
             adresa_completa: String::from_utf8_lossy(&c.adresa_completa).into_owned(),
             owner: c.owner.map(|a| a.to_string()),
+            judet: c.judet.map(|j| String::from_utf8_lossy(&j).into_owned()),
+            attested_by: c.attested_by.map(|a| a.to_string()),
         }))
     }
 
@@ -132,6 +194,49 @@ where
             )
         })
     }
+
+    fn get_pending_transfers(&self, account: AccountId, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<TransferOfferData>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let offers = api.pending_transfers(at, account).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query pending transfers",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(offers.into_iter().map(|o| TransferOfferData {
+            cui: o.cui,
+            from: o.from.to_string(),
+            to: o.to.to_string(),
+            expires_at: o.expires_at.into(),
+        }).collect())
+    }
+
+    fn get_company_history(&self, cui: u64, at: Option<<Block as BlockT>::Hash>) -> RpcResult<Vec<RevisionData>> {
+        let api = self.client.runtime_api();
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let history = api.company_history(at, cui).map_err(|err| {
+            ErrorObject::owned(
+                RUNTIME_ERROR,
+                "Unable to query company history",
+                Some(format!("{:?}", err)),
+            )
+        })?;
+
+        Ok(history.into_iter().map(|r| RevisionData {
+            block: r.block.into(),
+            author: r.author.map(|author| author.to_string()),
+            changes: r.changes.into_iter().map(|c| FieldChangeData {
+                field: field_name(&c.field).into(),
+                old: c.old.map(|v| field_value::<AccountId>(&c.field, &v)),
+                new: field_value::<AccountId>(&c.field, &c.new),
+            }).collect(),
+        }).collect())
+    }
 }
 
 const RUNTIME_ERROR: i32 = 1;
\ No newline at end of file
@@ -0,0 +1,31 @@
+//! Validarea codului unic de identificare (CUI).
+//!
+//! Un CUI are între 2 și 10 cifre, ultima fiind cifra de control. Cifrele rămase se înmulțesc,
+//! aliniate la dreapta, cu cheia `753217532`; suma produselor se înmulțește cu 10, iar restul
+//! împărțirii la 11 este cifra de control (10 devine 0).
+
+/// Cheia de testare, aliniată la dreapta față de cifrele CUI-ului fără cifra de control.
+const KEY: [u64; 9] = [7, 5, 3, 2, 1, 7, 5, 3, 2];
+
+/// Cel mai mare CUI posibil, cu 10 cifre.
+pub const MAX_CUI: u64 = 9_999_999_999;
+
+/// Verifică lungimea și cifra de control a unui CUI.
+pub fn is_valid(cui: u64) -> bool {
+	if !(10..=MAX_CUI).contains(&cui) {
+		return false
+	}
+
+	let control = cui % 10;
+	let mut rest = cui / 10;
+	let mut sum = 0;
+	for weight in KEY.iter().rev() {
+		if rest == 0 {
+			break
+		}
+		sum += (rest % 10) * weight;
+		rest /= 10;
+	}
+
+	(sum * 10 % 11) % 10 == control
+}
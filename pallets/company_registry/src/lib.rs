@@ -60,6 +60,10 @@ mod benchmarking;
 pub mod weights;
 pub use weights::*;
 
+// Migrările stocării între versiuni
+pub mod migrations;
+pub mod cui;
+
 // All pallet logic is defined in its own module and must be annotated by the `pallet` attribute.
 #[frame_support::pallet]
 pub mod pallet {
@@ -68,9 +72,34 @@ pub mod pallet {
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
 	use sp_std::vec::Vec;
-	use frame_support::traits::{Currency, WithdrawReasons, ExistenceRequirement, Get};
+	use frame_support::sp_runtime::traits::{One, Saturating, Zero};
+	use frame_support::traits::{
+		Currency, ExistenceRequirement, Get, Imbalance, OnUnbalanced, ReservableCurrency,
+		WithdrawReasons,
+	};
 
 	type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// Indicativul județului (`B`, `CJ`, `TM` etc.).
+	pub type CountyCode = BoundedVec<u8, ConstU32<2>>;
+
+	/// Numele reprezentantului legal, așa cum apare în identitatea on-chain.
+	pub type RepresentativeName = BoundedVec<u8, ConstU32<32>>;
+
+	/// Verifică identitatea celui care revendică o firmă.
+	pub trait IdentityVerifier<AccountId> {
+		/// `who` are o identitate confirmată de un registrator, cu numele legal `name`.
+		fn is_verified_as(who: &AccountId, name: &[u8]) -> bool;
+	}
+
+	impl<AccountId> IdentityVerifier<AccountId> for () {
+		fn is_verified_as(_: &AccountId, _: &[u8]) -> bool {
+			false
+		}
+	}
 
 	// The `Pallet` struct serves as a placeholder to implement traits, methods and dispatchables
 	// (`Call`s) in this pallet.
@@ -86,20 +115,58 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	 
 		// Tipuri de date
-		type CUI: Parameter + Member + Default + Copy + From<u16> + Into<u16>;
-		type Denumire: Parameter + Member + Default + Clone;
-		type CodInmatriculare: Parameter + Member + Default + Clone;
-		type EUID: Parameter + Member + Default + Clone;
-		type StareFirma: Parameter + Member + Default + Clone;
-		type AdresaCompleta: Parameter + Member + Default + Clone;
+		// Un CUI are până la 10 cifre, deci nu încape în `u32`
+		type CUI: Parameter + Member + Default + Copy + MaxEncodedLen + From<u64> + Into<u64>;
+		type Denumire: Parameter + Member + Default + Clone + MaxEncodedLen;
+		type CodInmatriculare: Parameter + Member + Default + Clone + MaxEncodedLen;
+		type EUID: Parameter + Member + Default + Clone + MaxEncodedLen;
+		type StareFirma: Parameter + Member + Default + Clone + MaxEncodedLen;
+		type AdresaCompleta: Parameter + Member + Default + Clone + MaxEncodedLen;
 	
-		type Currency: Currency<Self::AccountId>;
+		type Currency: ReservableCurrency<Self::AccountId>;
 		#[pallet::constant]
 		type QueryFee: Get<BalanceOf<Self>>;
+
+		// Originea care numește și revocă registratorii
+		type RegistrarOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		// Garanția pe care fiecare candidat la funcția de registrator o rezervă singur
+		#[pallet::constant]
+		type RegistrarBond: Get<BalanceOf<Self>>;
+		// Destinația garanțiilor confiscate
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		// Depozitul rezervat la revendicarea sau contestarea unei firme
+		#[pallet::constant]
+		type ClaimDeposit: Get<BalanceOf<Self>>;
+		// Perioada în care o revendicare poate fi contestată
+		#[pallet::constant]
+		type ChallengePeriod: Get<BlockNumberFor<Self>>;
+		// Cât timp, după perioada de contestare, are un registrator pentru a rezolva o
+		// revendicare contestată; apoi revendicarea cade și depozitele se eliberează
+		#[pallet::constant]
+		type ClaimResolutionPeriod: Get<BlockNumberFor<Self>>;
+		// Verificarea identității (de obicei prin `pallet_identity`)
+		type Identity: IdentityVerifier<Self::AccountId>;
+
+		// Numărul de blocuri în care destinatarul poate accepta un transfer
+		#[pallet::constant]
+		type TransferOfferPeriod: Get<BlockNumberFor<Self>>;
+		// Numărul maxim de oferte de transfer șterse la expirare într-un singur bloc
+		#[pallet::constant]
+		type MaxTransferExpiriesPerBlock: Get<u32>;
+		// Câte revizii se păstrează pentru fiecare firmă; cele mai vechi se șterg primele
+		#[pallet::constant]
+		type MaxRevisions: Get<u32>;
 	}
 
+   /// În câte blocuri consecutive se caută loc pentru ștergerea unei oferte expirate.
+   pub const MAX_EXPIRY_SCHEDULE_BLOCKS: u32 = 100;
+
+   /// Versiunea stocării; vezi `migrations` pentru trecerile între versiuni.
+   const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
    #[pallet::pallet]
-   #[pallet::without_storage_info]
+   #[pallet::storage_version(STORAGE_VERSION)]
    pub struct Pallet<T>(_);
 
    // Stocare
@@ -107,8 +174,9 @@ pub mod pallet {
    #[pallet::getter(fn companies)]
    pub type Companies<T: Config> = StorageMap<_, Blake2_128Concat, T::CUI, Company<T>>;
 
-   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
    #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
    pub struct Company<T: Config> {
 	   pub cui: T::CUI,
 	   pub denumire: T::Denumire,
@@ -117,8 +185,159 @@ pub mod pallet {
 	   pub stare_firma: T::StareFirma,
 	   pub adresa_completa: T::AdresaCompleta,
 	   pub owner: Option<T::AccountId>,
+	   // Județul sediului; lipsește la firmele adăugate înainte de registratori
+	   pub judet: Option<CountyCode>,
+	   // Registratorul care a confirmat ultima dată datele oficiale
+	   pub attested_by: Option<T::AccountId>,
+   }
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct Registrar<T: Config> {
+	   pub bond: BalanceOf<T>,
+	   // Județul în care poate scrie; `None` pentru toată țara
+	   pub scope: Option<CountyCode>,
+	   pub appointed_at: BlockNumberFor<T>,
+   }
+
+   #[pallet::storage]
+   #[pallet::getter(fn registrars)]
+   pub type Registrars<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Registrar<T>>;
+
+   // Candidații care și-au rezervat garanția și așteaptă numirea
+   #[pallet::storage]
+   #[pallet::getter(fn registrar_candidates)]
+   pub type RegistrarCandidates<T: Config> =
+	   StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct PendingClaim<T: Config> {
+	   pub claimant: T::AccountId,
+	   pub deposit: BalanceOf<T>,
+	   // Ultimul bloc în care revendicarea poate fi contestată
+	   pub challenge_end: BlockNumberFor<T>,
+	   // Contestatarul și depozitul lui
+	   pub challenge: Option<(T::AccountId, BalanceOf<T>)>,
+   }
+
+   // Revendicările care așteaptă aprobarea sau trecerea perioadei de contestare
+   #[pallet::storage]
+   #[pallet::getter(fn pending_claims)]
+   pub type PendingClaims<T: Config> = StorageMap<_, Blake2_128Concat, T::CUI, PendingClaim<T>>;
+
+   // Reprezentantul legal al firmei, stabilit de registrator
+   #[pallet::storage]
+   #[pallet::getter(fn legal_representatives)]
+   pub type LegalRepresentatives<T: Config> =
+	   StorageMap<_, Blake2_128Concat, T::CUI, RepresentativeName>;
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct TransferOffer<T: Config> {
+	   pub from: T::AccountId,
+	   pub to: T::AccountId,
+	   // Ultimul bloc în care transferul poate fi acceptat
+	   pub expires_at: BlockNumberFor<T>,
+   }
+
+   // Cel mult o ofertă de transfer pe firmă
+   #[pallet::storage]
+   #[pallet::getter(fn transfer_offers)]
+   pub type TransferOffers<T: Config> = StorageMap<_, Blake2_128Concat, T::CUI, TransferOffer<T>>;
+
+   // Index secundar: ofertele primite de fiecare cont
+   #[pallet::storage]
+   pub type TransfersByRecipient<T: Config> =
+	   StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::CUI, ()>;
+
+   // Ofertele de transfer care se șterg la un anumit bloc, după expirare
+   #[pallet::storage]
+   pub type TransferExpiries<T: Config> = StorageMap<
+	   _,
+	   Twox64Concat,
+	   BlockNumberFor<T>,
+	   BoundedVec<T::CUI, T::MaxTransferExpiriesPerBlock>,
+	   ValueQuery,
+   >;
+
+   /// Câmpurile unei firme urmărite în istoric: cele oficiale și proprietarul.
+   #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   pub enum CompanyField {
+	   Denumire,
+	   CodInmatriculare,
+	   Euid,
+	   StareFirma,
+	   AdresaCompleta,
+	   Judet,
+	   Owner,
+   }
+
+   /// Valoarea unui câmp urmărit în istoric, cu tipul lui.
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub enum FieldValue<T: Config> {
+	   Denumire(T::Denumire),
+	   CodInmatriculare(T::CodInmatriculare),
+	   Euid(T::EUID),
+	   StareFirma(T::StareFirma),
+	   AdresaCompleta(T::AdresaCompleta),
+	   Judet(CountyCode),
+	   Owner(T::AccountId),
+   }
+
+   impl<T: Config> FieldValue<T> {
+	   pub fn field(&self) -> CompanyField {
+		   match self {
+			   Self::Denumire(_) => CompanyField::Denumire,
+			   Self::CodInmatriculare(_) => CompanyField::CodInmatriculare,
+			   Self::Euid(_) => CompanyField::Euid,
+			   Self::StareFirma(_) => CompanyField::StareFirma,
+			   Self::AdresaCompleta(_) => CompanyField::AdresaCompleta,
+			   Self::Judet(_) => CompanyField::Judet,
+			   Self::Owner(_) => CompanyField::Owner,
+		   }
+	   }
+   }
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct FieldChange<T: Config> {
+	   // Lipsește la crearea firmei, la primul proprietar și la completarea județului
+	   pub old: Option<FieldValue<T>>,
+	   pub new: FieldValue<T>,
    }
 
+   /// Un câmp apare cel mult o dată într-o revizie.
+   pub type MaxFieldChanges = ConstU32<7>;
+
+   #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+   #[scale_info(skip_type_params(T))]
+   #[codec(mel_bound())]
+   pub struct Revision<T: Config> {
+	   // Lipsește la modificările făcute de `RegistrarOrigin`, care nu are cont
+	   pub author: Option<T::AccountId>,
+	   pub block: BlockNumberFor<T>,
+	   pub changes: BoundedVec<FieldChange<T>, MaxFieldChanges>,
+   }
+
+   // Istoricul modificărilor fiecărei firme, de la cea mai veche revizie păstrată. Este o
+   // singură valoare, citită și rescrisă întreagă la fiecare revizie
+   #[pallet::storage]
+   #[pallet::getter(fn company_history)]
+   pub type CompanyHistory<T: Config> =
+	   StorageMap<_, Blake2_128Concat, T::CUI, BoundedVec<Revision<T>, T::MaxRevisions>, ValueQuery>;
+
+   // Revendicarea cere identitatea reprezentantului legal
+   #[pallet::storage]
+   #[pallet::getter(fn identity_required)]
+   pub type IdentityRequired<T: Config> = StorageValue<_, bool, ValueQuery>;
+
    	#[pallet::storage]
 	#[pallet::getter(fn paid_queries)]
 	pub type PaidQueries<T: Config> = StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Blake2_128Concat, T::CUI, BlockNumberFor<T>>;
@@ -127,10 +346,36 @@ pub mod pallet {
    #[pallet::generate_deposit(pub(super) fn deposit_event)]
    pub enum Event<T: Config> {
 	   CompanyAdded { cui: T::CUI, sender: T::AccountId },
-	   CompanyUpdated { cui: T::CUI, sender: T::AccountId },
+	   CompanyUpdated { cui: T::CUI, sender: T::AccountId, fields: Vec<CompanyField> },
 	   CompanyClaimed { cui: T::CUI, owner: T::AccountId },
-	   CompanyOwnershipTransferred { cui: T::CUI, new_owner: T::AccountId },
+	   CompanyOwnershipTransferred {
+		   cui: T::CUI,
+		   previous_owner: T::AccountId,
+		   new_owner: T::AccountId,
+	   },
 	   CompanyDataPaidFor { cui: T::CUI, caller: T::AccountId },
+	   RegistrarAppointed { who: T::AccountId, scope: Option<CountyCode>, bond: BalanceOf<T> },
+	   RegistrarRemoved { who: T::AccountId, slashed: BalanceOf<T> },
+	   RegistrarBondPosted { who: T::AccountId, bond: BalanceOf<T> },
+	   RegistrarBondWithdrawn { who: T::AccountId, bond: BalanceOf<T> },
+	   // Județul unei firme migrate fără județ, completat de root
+	   CompanyCountySet { cui: T::CUI, judet: CountyCode },
+	   ClaimSubmitted { cui: T::CUI, claimant: T::AccountId, challenge_end: BlockNumberFor<T> },
+	   ClaimChallenged { cui: T::CUI, challenger: T::AccountId },
+	   ClaimRejected { cui: T::CUI, claimant: T::AccountId, slashed: BalanceOf<T> },
+	   // Revendicarea contestată nu a fost rezolvată la timp; depozitele au fost eliberate
+	   ClaimExpired { cui: T::CUI, claimant: T::AccountId },
+	   LegalRepresentativeSet { cui: T::CUI, name: RepresentativeName },
+	   IdentityRequirementChanged { required: bool },
+	   TransferOffered {
+		   cui: T::CUI,
+		   from: T::AccountId,
+		   to: T::AccountId,
+		   expires_at: BlockNumberFor<T>,
+	   },
+	   TransferCancelled { cui: T::CUI, to: T::AccountId },
+	   // Oferta nu a fost acceptată la timp și a fost ștearsă
+	   TransferOfferExpired { cui: T::CUI, to: T::AccountId },
    }
 
    #[pallet::error]
@@ -139,6 +384,43 @@ pub mod pallet {
 	   CompanyNotFound,
 	   CompanyAlreadyClaimed,
 	   NotCompanyOwner,
+	   // CUI-ul are o lungime greșită sau cifra de control nu se potrivește
+	   InvalidCui,
+	   NotRegistrar,
+	   AlreadyRegistrar,
+	   // Contul nu are fonduri pentru garanție
+	   InsufficientBond,
+	   // Garanția de registrator a fost deja depusă
+	   BondAlreadyPosted,
+	   // Candidatul nu și-a depus garanția
+	   NoBondPosted,
+	   // Firma are deja un județ; se schimbă prin `update_company`
+	   CountyAlreadySet,
+	   // Firma este în afara județului registratorului
+	   OutOfScope,
+	   // Există deja o revendicare în curs pentru firmă
+	   ClaimPending,
+	   NoPendingClaim,
+	   // Contul nu are fonduri pentru depozitul revendicării sau al contestației
+	   InsufficientDeposit,
+	   // Perioada de contestare nu s-a încheiat
+	   ChallengePeriodActive,
+	   ChallengePeriodOver,
+	   AlreadyChallenged,
+	   CannotChallengeOwnClaim,
+	   // O revendicare contestată se rezolvă de registrator până la expirarea termenului
+	   ClaimDisputed,
+	   // Firma nu are un reprezentant legal înregistrat
+	   MissingRepresentative,
+	   // Identitatea nu corespunde reprezentantului legal
+	   IdentityNotVerified,
+	   NoTransferOffer,
+	   NotTransferRecipient,
+	   // Oferta de transfer a expirat
+	   TransferExpired,
+	   CannotTransferToSelf,
+	   // Nu s-a găsit loc pentru ștergerea ofertei la expirare
+	   TransferQueueFull,
    }
 
    // Funcții apelabile
@@ -154,11 +436,27 @@ pub mod pallet {
 		   euid: T::EUID,
 		   stare_firma: T::StareFirma,
 		   adresa_completa: T::AdresaCompleta,
+		   judet: CountyCode,
 	   ) -> DispatchResult {
 		   let sender = ensure_signed(origin)?;
+		   Self::ensure_registrar_for(&sender, Some(&judet))?;
 
+		   ensure!(crate::cui::is_valid(cui.into()), Error::<T>::InvalidCui);
 		   ensure!(!Companies::<T>::contains_key(&cui), Error::<T>::CompanyAlreadyExists);
 
+		   // Prima revizie păstrează valorile inițiale
+		   let changes = sp_std::vec![
+			   FieldValue::Denumire(denumire.clone()),
+			   FieldValue::CodInmatriculare(cod_inmatriculare.clone()),
+			   FieldValue::Euid(euid.clone()),
+			   FieldValue::StareFirma(stare_firma.clone()),
+			   FieldValue::AdresaCompleta(adresa_completa.clone()),
+			   FieldValue::Judet(judet.clone()),
+		   ]
+		   .into_iter()
+		   .map(|new| FieldChange { old: None, new })
+		   .collect();
+
 		   let company = Company {
 			   cui: cui.clone(),
 			   denumire,
@@ -167,9 +465,12 @@ pub mod pallet {
 			   stare_firma,
 			   adresa_completa,
 			   owner: None,
+			   judet: Some(judet),
+			   attested_by: Some(sender.clone()),
 		   };
 
 		   Companies::<T>::insert(cui.clone(), company);
+		   Self::record_revision(cui, Some(&sender), changes);
 
 		   Self::deposit_event(Event::CompanyAdded { cui, sender });
 		   Ok(())
@@ -185,32 +486,52 @@ pub mod pallet {
 		   euid: Option<T::EUID>,
 		   stare_firma: Option<T::StareFirma>,
 		   adresa_completa: Option<T::AdresaCompleta>,
+		   judet: Option<CountyCode>,
 	   ) -> DispatchResult {
 		   let sender = ensure_signed(origin)?;
 
-		   Companies::<T>::try_mutate(&cui, |maybe_company| -> DispatchResult {
+		   // Se rețin doar câmpurile a căror valoare se schimbă
+		   let changes = Companies::<T>::try_mutate(&cui, |maybe_company| -> Result<_, DispatchError> {
 			   let company = maybe_company.as_mut().ok_or(Error::<T>::CompanyNotFound)?;
+			   Self::ensure_registrar_for(&sender, company.judet.as_ref())?;
 
-			   if let Some(new_denumire) = denumire {
-				   company.denumire = new_denumire;
+			   let mut changes = Vec::new();
+			   let mut change = |old, new| changes.push(FieldChange { old, new });
+			   if let Some(new) = denumire.filter(|new| *new != company.denumire) {
+				   let old = sp_std::mem::replace(&mut company.denumire, new.clone());
+				   change(Some(FieldValue::Denumire(old)), FieldValue::Denumire(new));
+			   }
+			   if let Some(new) = cod_inmatriculare.filter(|new| *new != company.cod_inmatriculare) {
+				   let old = sp_std::mem::replace(&mut company.cod_inmatriculare, new.clone());
+				   change(Some(FieldValue::CodInmatriculare(old)), FieldValue::CodInmatriculare(new));
 			   }
-			   if let Some(new_cod_inmatriculare) = cod_inmatriculare {
-				   company.cod_inmatriculare = new_cod_inmatriculare;
+			   if let Some(new) = euid.filter(|new| *new != company.euid) {
+				   let old = sp_std::mem::replace(&mut company.euid, new.clone());
+				   change(Some(FieldValue::Euid(old)), FieldValue::Euid(new));
 			   }
-			   if let Some(new_euid) = euid {
-				   company.euid = new_euid;
+			   if let Some(new) = stare_firma.filter(|new| *new != company.stare_firma) {
+				   let old = sp_std::mem::replace(&mut company.stare_firma, new.clone());
+				   change(Some(FieldValue::StareFirma(old)), FieldValue::StareFirma(new));
 			   }
-			   if let Some(new_stare_firma) = stare_firma {
-				   company.stare_firma = new_stare_firma;
+			   if let Some(new) = adresa_completa.filter(|new| *new != company.adresa_completa) {
+				   let old = sp_std::mem::replace(&mut company.adresa_completa, new.clone());
+				   change(Some(FieldValue::AdresaCompleta(old)), FieldValue::AdresaCompleta(new));
 			   }
-			   if let Some(new_adresa_completa) = adresa_completa {
-				   company.adresa_completa = new_adresa_completa;
+			   // Mutarea sediului cere drepturi și în județul nou
+			   if let Some(new) = judet.filter(|new| company.judet.as_ref() != Some(new)) {
+				   Self::ensure_registrar_for(&sender, Some(&new))?;
+				   let old = company.judet.replace(new.clone());
+				   change(old.map(FieldValue::Judet), FieldValue::Judet(new));
 			   }
+			   company.attested_by = Some(sender.clone());
 
-			   Ok(())
+			   Ok(changes)
 		   })?;
 
-		   Self::deposit_event(Event::CompanyUpdated { cui, sender });
+		   let fields = changes.iter().map(|change: &FieldChange<T>| change.new.field()).collect();
+		   Self::record_revision(cui, Some(&sender), changes);
+
+		   Self::deposit_event(Event::CompanyUpdated { cui, sender, fields });
 		   Ok(())
 	   }
 
@@ -221,41 +542,70 @@ pub mod pallet {
 		   cui: T::CUI,
 	   ) -> DispatchResult {
 		   let claimer = ensure_signed(origin)?;
-   
-		   Companies::<T>::try_mutate(&cui, |maybe_company| -> DispatchResult {
-			   let company = maybe_company.as_mut().ok_or(Error::<T>::CompanyNotFound)?;
-			   
-			   ensure!(company.owner.is_none(), Error::<T>::CompanyAlreadyClaimed);
-   
-			   company.owner = Some(claimer.clone());
-   
-			   Ok(())
-		   })?;
-   
-		   Self::deposit_event(Event::CompanyClaimed { cui, owner: claimer });
+
+		   let company = Companies::<T>::get(&cui).ok_or(Error::<T>::CompanyNotFound)?;
+		   ensure!(company.owner.is_none(), Error::<T>::CompanyAlreadyClaimed);
+		   ensure!(!PendingClaims::<T>::contains_key(&cui), Error::<T>::ClaimPending);
+
+		   if IdentityRequired::<T>::get() {
+			   let name = LegalRepresentatives::<T>::get(&cui)
+				   .ok_or(Error::<T>::MissingRepresentative)?;
+			   ensure!(T::Identity::is_verified_as(&claimer, &name), Error::<T>::IdentityNotVerified);
+		   }
+
+		   let deposit = T::ClaimDeposit::get();
+		   T::Currency::reserve(&claimer, deposit).map_err(|_| Error::<T>::InsufficientDeposit)?;
+
+		   // Revendicarea devine definitivă după aprobare sau după perioada de contestare
+		   let challenge_end =
+			   <frame_system::Pallet<T>>::block_number().saturating_add(T::ChallengePeriod::get());
+		   PendingClaims::<T>::insert(&cui, PendingClaim {
+			   claimant: claimer.clone(),
+			   deposit,
+			   challenge_end,
+			   challenge: None,
+		   });
+
+		   Self::deposit_event(Event::ClaimSubmitted { cui, claimant: claimer, challenge_end });
 		   Ok(())
 	   }
 
+	   /// Propune transferul firmei către `new_owner`, care trebuie să îl accepte înainte de
+	   /// expirare. O ofertă nouă o înlocuiește pe cea existentă, care este retrasă.
 	   #[pallet::call_index(3)]
 	   #[pallet::weight(10_000)]
-	   pub fn transfer_company_ownership(
+	   pub fn offer_company_transfer(
 		   origin: OriginFor<T>,
 		   cui: T::CUI,
 		   new_owner: T::AccountId,
 	   ) -> DispatchResult {
 		   let current_owner = ensure_signed(origin)?;
-   
-		   Companies::<T>::try_mutate(&cui, |maybe_company| -> DispatchResult {
-			   let company = maybe_company.as_mut().ok_or(Error::<T>::CompanyNotFound)?;
-			   
-			   ensure!(company.owner == Some(current_owner.clone()), Error::<T>::NotCompanyOwner);
-   
-			   company.owner = Some(new_owner.clone());
-   
-			   Ok(())
-		   })?;
-   
-		   Self::deposit_event(Event::CompanyOwnershipTransferred { cui, new_owner });
+
+		   let company = Companies::<T>::get(&cui).ok_or(Error::<T>::CompanyNotFound)?;
+		   ensure!(company.owner == Some(current_owner.clone()), Error::<T>::NotCompanyOwner);
+		   ensure!(new_owner != current_owner, Error::<T>::CannotTransferToSelf);
+
+		   if let Some(previous) = TransferOffers::<T>::get(&cui) {
+			   TransfersByRecipient::<T>::remove(&previous.to, &cui);
+			   Self::unschedule_expiry(previous.expires_at, cui);
+			   Self::deposit_event(Event::TransferCancelled { cui, to: previous.to });
+		   }
+		   let expires_at =
+			   <frame_system::Pallet<T>>::block_number().saturating_add(T::TransferOfferPeriod::get());
+		   Self::schedule_expiry(expires_at.saturating_add(One::one()), cui)?;
+		   TransferOffers::<T>::insert(&cui, TransferOffer {
+			   from: current_owner.clone(),
+			   to: new_owner.clone(),
+			   expires_at,
+		   });
+		   TransfersByRecipient::<T>::insert(&new_owner, &cui, ());
+
+		   Self::deposit_event(Event::TransferOffered {
+			   cui,
+			   from: current_owner,
+			   to: new_owner,
+			   expires_at,
+		   });
 		   Ok(())
 	   }
 
@@ -279,15 +629,417 @@ pub mod pallet {
    
 		   Ok(())
 	   }
+
+	   /// Numește registrator un candidat care și-a depus garanția prin
+	   /// `post_registrar_bond`.
+	   #[pallet::call_index(5)]
+	   #[pallet::weight(10_000)]
+	   pub fn appoint_registrar(
+		   origin: OriginFor<T>,
+		   who: T::AccountId,
+		   scope: Option<CountyCode>,
+	   ) -> DispatchResult {
+		   T::RegistrarOrigin::ensure_origin(origin)?;
+		   ensure!(!Registrars::<T>::contains_key(&who), Error::<T>::AlreadyRegistrar);
+
+		   let bond = RegistrarCandidates::<T>::take(&who).ok_or(Error::<T>::NoBondPosted)?;
+		   Registrars::<T>::insert(&who, Registrar {
+			   bond,
+			   scope: scope.clone(),
+			   appointed_at: <frame_system::Pallet<T>>::block_number(),
+		   });
+
+		   Self::deposit_event(Event::RegistrarAppointed { who, scope, bond });
+		   Ok(())
+	   }
+
+	   /// Revocă un registrator. Cu `slash`, garanția este confiscată; altfel se eliberează.
+	   #[pallet::call_index(6)]
+	   #[pallet::weight(10_000)]
+	   pub fn remove_registrar(
+		   origin: OriginFor<T>,
+		   who: T::AccountId,
+		   slash: bool,
+	   ) -> DispatchResult {
+		   T::RegistrarOrigin::ensure_origin(origin)?;
+		   let registrar = Registrars::<T>::take(&who).ok_or(Error::<T>::NotRegistrar)?;
+
+		   let slashed = if slash {
+			   Self::slash_reserved(&who, registrar.bond)
+		   } else {
+			   T::Currency::unreserve(&who, registrar.bond);
+			   Zero::zero()
+		   };
+
+		   Self::deposit_event(Event::RegistrarRemoved { who, slashed });
+		   Ok(())
+	   }
+
+	   /// Contestă o revendicare în curs; contestatarul rezervă același depozit ca revendicantul.
+	   #[pallet::call_index(7)]
+	   #[pallet::weight(10_000)]
+	   pub fn challenge_claim(origin: OriginFor<T>, cui: T::CUI) -> DispatchResult {
+		   let challenger = ensure_signed(origin)?;
+
+		   PendingClaims::<T>::try_mutate(&cui, |maybe_claim| -> DispatchResult {
+			   let claim = maybe_claim.as_mut().ok_or(Error::<T>::NoPendingClaim)?;
+			   ensure!(claim.claimant != challenger, Error::<T>::CannotChallengeOwnClaim);
+			   ensure!(claim.challenge.is_none(), Error::<T>::AlreadyChallenged);
+			   ensure!(
+				   <frame_system::Pallet<T>>::block_number() <= claim.challenge_end,
+				   Error::<T>::ChallengePeriodOver
+			   );
+
+			   let deposit = T::ClaimDeposit::get();
+			   T::Currency::reserve(&challenger, deposit)
+				   .map_err(|_| Error::<T>::InsufficientDeposit)?;
+			   claim.challenge = Some((challenger.clone(), deposit));
+			   Ok(())
+		   })?;
+
+		   Self::deposit_event(Event::ClaimChallenged { cui, challenger });
+		   Ok(())
+	   }
+
+	   /// Aprobă o revendicare, contestată sau nu. Depozitul contestatarului este confiscat.
+	   #[pallet::call_index(8)]
+	   #[pallet::weight(10_000)]
+	   pub fn approve_claim(origin: OriginFor<T>, cui: T::CUI) -> DispatchResult {
+		   let registrar = ensure_signed(origin)?;
+		   let company = Companies::<T>::get(&cui).ok_or(Error::<T>::CompanyNotFound)?;
+		   Self::ensure_registrar_for(&registrar, company.judet.as_ref())?;
+
+		   let claim = PendingClaims::<T>::take(&cui).ok_or(Error::<T>::NoPendingClaim)?;
+		   if let Some((challenger, deposit)) = &claim.challenge {
+			   Self::slash_reserved(challenger, *deposit);
+		   }
+		   Self::finalize(cui, claim, &registrar);
+		   Ok(())
+	   }
+
+	   /// Respinge o revendicare frauduloasă. Depozitul revendicantului este confiscat, iar
+	   /// contestatarul își primește depozitul înapoi.
+	   #[pallet::call_index(9)]
+	   #[pallet::weight(10_000)]
+	   pub fn reject_claim(origin: OriginFor<T>, cui: T::CUI) -> DispatchResult {
+		   let registrar = ensure_signed(origin)?;
+		   let company = Companies::<T>::get(&cui).ok_or(Error::<T>::CompanyNotFound)?;
+		   Self::ensure_registrar_for(&registrar, company.judet.as_ref())?;
+
+		   let claim = PendingClaims::<T>::take(&cui).ok_or(Error::<T>::NoPendingClaim)?;
+		   let slashed = Self::slash_reserved(&claim.claimant, claim.deposit);
+		   if let Some((challenger, deposit)) = claim.challenge {
+			   T::Currency::unreserve(&challenger, deposit);
+		   }
+
+		   Self::deposit_event(Event::ClaimRejected { cui, claimant: claim.claimant, slashed });
+		   Ok(())
+	   }
+
+	   /// Finalizează o revendicare necontestată după perioada de contestare; oricine o poate
+	   /// apela. O revendicare contestată pe care niciun registrator nu a rezolvat-o în
+	   /// `ClaimResolutionPeriod` cade, iar ambele depozite se eliberează.
+	   #[pallet::call_index(10)]
+	   #[pallet::weight(10_000)]
+	   pub fn finalize_claim(origin: OriginFor<T>, cui: T::CUI) -> DispatchResult {
+		   ensure_signed(origin)?;
+
+		   let claim = PendingClaims::<T>::get(&cui).ok_or(Error::<T>::NoPendingClaim)?;
+		   let now = <frame_system::Pallet<T>>::block_number();
+		   ensure!(now > claim.challenge_end, Error::<T>::ChallengePeriodActive);
+
+		   if let Some((challenger, deposit)) = &claim.challenge {
+			   let resolve_by = claim.challenge_end.saturating_add(T::ClaimResolutionPeriod::get());
+			   ensure!(now > resolve_by, Error::<T>::ClaimDisputed);
+
+			   PendingClaims::<T>::remove(&cui);
+			   T::Currency::unreserve(challenger, *deposit);
+			   T::Currency::unreserve(&claim.claimant, claim.deposit);
+			   Self::deposit_event(Event::ClaimExpired { cui, claimant: claim.claimant });
+			   return Ok(())
+		   }
+
+		   PendingClaims::<T>::remove(&cui);
+		   let claimant = claim.claimant.clone();
+		   Self::finalize(cui, claim, &claimant);
+		   Ok(())
+	   }
+
+	   /// Înregistrează reprezentantul legal al firmei, folosit la verificarea identității.
+	   #[pallet::call_index(11)]
+	   #[pallet::weight(10_000)]
+	   pub fn set_legal_representative(
+		   origin: OriginFor<T>,
+		   cui: T::CUI,
+		   name: RepresentativeName,
+	   ) -> DispatchResult {
+		   let registrar = ensure_signed(origin)?;
+		   let company = Companies::<T>::get(&cui).ok_or(Error::<T>::CompanyNotFound)?;
+		   Self::ensure_registrar_for(&registrar, company.judet.as_ref())?;
+
+		   LegalRepresentatives::<T>::insert(&cui, &name);
+		   Self::deposit_event(Event::LegalRepresentativeSet { cui, name });
+		   Ok(())
+	   }
+
+	   /// Activează sau dezactivează verificarea identității la revendicare.
+	   #[pallet::call_index(12)]
+	   #[pallet::weight(10_000)]
+	   pub fn set_identity_required(origin: OriginFor<T>, required: bool) -> DispatchResult {
+		   T::RegistrarOrigin::ensure_origin(origin)?;
+		   IdentityRequired::<T>::put(required);
+		   Self::deposit_event(Event::IdentityRequirementChanged { required });
+		   Ok(())
+	   }
+
+	   /// Acceptă oferta de transfer; doar destinatarul o poate accepta, până la expirare.
+	   #[pallet::call_index(13)]
+	   #[pallet::weight(10_000)]
+	   pub fn accept_company_transfer(origin: OriginFor<T>, cui: T::CUI) -> DispatchResult {
+		   let new_owner = ensure_signed(origin)?;
+
+		   let offer = TransferOffers::<T>::get(&cui).ok_or(Error::<T>::NoTransferOffer)?;
+		   ensure!(offer.to == new_owner, Error::<T>::NotTransferRecipient);
+		   ensure!(
+			   <frame_system::Pallet<T>>::block_number() <= offer.expires_at,
+			   Error::<T>::TransferExpired
+		   );
+
+		   Companies::<T>::try_mutate(&cui, |maybe_company| -> DispatchResult {
+			   let company = maybe_company.as_mut().ok_or(Error::<T>::CompanyNotFound)?;
+			   // Oferta este valabilă doar cât timp proprietarul nu s-a schimbat
+			   ensure!(company.owner.as_ref() == Some(&offer.from), Error::<T>::NotCompanyOwner);
+			   company.owner = Some(new_owner.clone());
+			   Ok(())
+		   })?;
+		   TransferOffers::<T>::remove(&cui);
+		   TransfersByRecipient::<T>::remove(&new_owner, &cui);
+		   Self::unschedule_expiry(offer.expires_at, cui);
+		   Self::record_revision(cui, Some(&new_owner), sp_std::vec![FieldChange {
+			   old: Some(FieldValue::Owner(offer.from.clone())),
+			   new: FieldValue::Owner(new_owner.clone()),
+		   }]);
+
+		   Self::deposit_event(Event::CompanyOwnershipTransferred {
+			   cui,
+			   previous_owner: offer.from,
+			   new_owner,
+		   });
+		   Ok(())
+	   }
+
+	   /// Retrage oferta de transfer. Doar cel care a făcut-o o poate retrage.
+	   #[pallet::call_index(14)]
+	   #[pallet::weight(10_000)]
+	   pub fn cancel_company_transfer(origin: OriginFor<T>, cui: T::CUI) -> DispatchResult {
+		   let owner = ensure_signed(origin)?;
+
+		   let offer = TransferOffers::<T>::get(&cui).ok_or(Error::<T>::NoTransferOffer)?;
+		   ensure!(offer.from == owner, Error::<T>::NotCompanyOwner);
+		   TransferOffers::<T>::remove(&cui);
+		   TransfersByRecipient::<T>::remove(&offer.to, &cui);
+		   Self::unschedule_expiry(offer.expires_at, cui);
+
+		   Self::deposit_event(Event::TransferCancelled { cui, to: offer.to });
+		   Ok(())
+	   }
+
+	   /// Candidatul la funcția de registrator își rezervă singur garanția; numirea o face
+	   /// apoi `RegistrarOrigin`.
+	   #[pallet::call_index(15)]
+	   #[pallet::weight(10_000)]
+	   pub fn post_registrar_bond(origin: OriginFor<T>) -> DispatchResult {
+		   let who = ensure_signed(origin)?;
+		   ensure!(!Registrars::<T>::contains_key(&who), Error::<T>::AlreadyRegistrar);
+		   ensure!(!RegistrarCandidates::<T>::contains_key(&who), Error::<T>::BondAlreadyPosted);
+
+		   let bond = T::RegistrarBond::get();
+		   T::Currency::reserve(&who, bond).map_err(|_| Error::<T>::InsufficientBond)?;
+		   RegistrarCandidates::<T>::insert(&who, bond);
+
+		   Self::deposit_event(Event::RegistrarBondPosted { who, bond });
+		   Ok(())
+	   }
+
+	   /// Candidatul care nu a fost încă numit își retrage garanția.
+	   #[pallet::call_index(16)]
+	   #[pallet::weight(10_000)]
+	   pub fn withdraw_registrar_bond(origin: OriginFor<T>) -> DispatchResult {
+		   let who = ensure_signed(origin)?;
+		   let bond = RegistrarCandidates::<T>::take(&who).ok_or(Error::<T>::NoBondPosted)?;
+		   T::Currency::unreserve(&who, bond);
+
+		   Self::deposit_event(Event::RegistrarBondWithdrawn { who, bond });
+		   Ok(())
+	   }
+
+	   /// Completează județul unei firme care nu are unul (cele migrate la versiunea 3), ca
+	   /// registratorii acelui județ să o poată modifica. Doar `RegistrarOrigin`, ca la numirea
+	   /// registratorilor.
+	   #[pallet::call_index(17)]
+	   #[pallet::weight(10_000)]
+	   pub fn set_company_county(
+		   origin: OriginFor<T>,
+		   cui: T::CUI,
+		   judet: CountyCode,
+	   ) -> DispatchResult {
+		   T::RegistrarOrigin::ensure_origin(origin)?;
+
+		   Companies::<T>::try_mutate(&cui, |maybe_company| -> DispatchResult {
+			   let company = maybe_company.as_mut().ok_or(Error::<T>::CompanyNotFound)?;
+			   ensure!(company.judet.is_none(), Error::<T>::CountyAlreadySet);
+			   company.judet = Some(judet.clone());
+			   Ok(())
+		   })?;
+		   Self::record_revision(cui, None, sp_std::vec![FieldChange {
+			   old: None,
+			   new: FieldValue::Judet(judet.clone()),
+		   }]);
+
+		   Self::deposit_event(Event::CompanyCountySet { cui, judet });
+		   Ok(())
+	   }
+   }
+
+   #[pallet::hooks]
+   impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+	   fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+		   Self::expire_transfers(now)
+	   }
    }
 
    // Implementare separată pentru metodele interne
 	impl<T: Config> Pallet<T> {
+		/// Contul este registrator, iar județul firmei se află în aria lui.
+		fn ensure_registrar_for(who: &T::AccountId, judet: Option<&CountyCode>) -> DispatchResult {
+			let registrar = Registrars::<T>::get(who).ok_or(Error::<T>::NotRegistrar)?;
+			if let Some(scope) = registrar.scope {
+				ensure!(judet == Some(&scope), Error::<T>::OutOfScope);
+			}
+			Ok(())
+		}
+
+		/// Confiscă o sumă rezervată și o trimite către `T::Slashed`.
+		fn slash_reserved(who: &T::AccountId, amount: BalanceOf<T>) -> BalanceOf<T> {
+			let (imbalance, _) = T::Currency::slash_reserved(who, amount);
+			let slashed = imbalance.peek();
+			T::Slashed::on_unbalanced(imbalance);
+			slashed
+		}
+
+		/// Dă firma revendicantului, îi eliberează depozitul și adaugă schimbarea proprietarului
+		/// în istoric, cu `author` ca autor.
+		fn finalize(cui: T::CUI, claim: PendingClaim<T>, author: &T::AccountId) {
+			T::Currency::unreserve(&claim.claimant, claim.deposit);
+			let previous = Companies::<T>::mutate(&cui, |maybe_company| {
+				maybe_company
+					.as_mut()
+					.map(|company| company.owner.replace(claim.claimant.clone()))
+			});
+			if let Some(previous) = previous {
+				Self::record_revision(cui, Some(author), sp_std::vec![FieldChange {
+					old: previous.map(FieldValue::Owner),
+					new: FieldValue::Owner(claim.claimant.clone()),
+				}]);
+			}
+			Self::deposit_event(Event::CompanyClaimed { cui, owner: claim.claimant });
+		}
+
 		pub fn get_company_data(cui: T::CUI, caller: T::AccountId) -> Option<Company<T>> {
 			Companies::<T>::get(cui)
 		}
 		pub fn has_paid_for_company_data(caller: T::AccountId, cui: T::CUI) -> bool {
 			PaidQueries::<T>::contains_key(caller, cui)
 		}
+
+		/// Adaugă o revizie în istoricul firmei. Dacă istoricul este plin, cea mai veche revizie
+		/// se șterge. `remove(0)` mută toate reviziile, deci costă O(`MaxRevisions`), la fel ca
+		/// citirea și rescrierea istoricului, care oricum are loc la fiecare revizie; o coadă
+		/// circulară nu ar schimba costul total.
+		fn record_revision(
+			cui: T::CUI,
+			author: Option<&T::AccountId>,
+			changes: Vec<FieldChange<T>>,
+		) {
+			if changes.is_empty() {
+				return
+			}
+			let revision = Revision {
+				author: author.cloned(),
+				block: <frame_system::Pallet<T>>::block_number(),
+				// Fiecare câmp apare cel mult o dată
+				changes: BoundedVec::truncate_from(changes),
+			};
+			CompanyHistory::<T>::mutate(cui, |history| {
+				if !history.is_empty() && history.len() as u32 >= T::MaxRevisions::get() {
+					history.remove(0);
+				}
+				// Eșuează doar dacă `MaxRevisions` este zero
+				let _ = history.try_push(revision);
+			});
+		}
+
+		/// Programează ștergerea ofertei la primul bloc începând cu `earliest` care mai are loc.
+		/// Se caută în cel mult `MAX_EXPIRY_SCHEDULE_BLOCKS` blocuri.
+		fn schedule_expiry(earliest: BlockNumberFor<T>, cui: T::CUI) -> DispatchResult {
+			let mut block = earliest;
+			for _ in 0..MAX_EXPIRY_SCHEDULE_BLOCKS {
+				if TransferExpiries::<T>::try_append(block, cui).is_ok() {
+					return Ok(())
+				}
+				block = block.saturating_add(One::one());
+			}
+			Err(Error::<T>::TransferQueueFull.into())
+		}
+
+		/// Eliberează locul ocupat în coadă de oferta care expiră la `expires_at`, când oferta
+		/// este acceptată, retrasă sau înlocuită. Locul se caută ca în `schedule_expiry`.
+		fn unschedule_expiry(expires_at: BlockNumberFor<T>, cui: T::CUI) {
+			let mut block = expires_at.saturating_add(One::one());
+			for _ in 0..MAX_EXPIRY_SCHEDULE_BLOCKS {
+				let mut scheduled = TransferExpiries::<T>::get(block);
+				if let Some(position) = scheduled.iter().position(|c| *c == cui) {
+					scheduled.remove(position);
+					if scheduled.is_empty() {
+						TransferExpiries::<T>::remove(block);
+					} else {
+						TransferExpiries::<T>::insert(block, scheduled);
+					}
+					return
+				}
+				block = block.saturating_add(One::one());
+			}
+		}
+
+		/// Șterge ofertele expirate programate la blocul `now`.
+		fn expire_transfers(now: BlockNumberFor<T>) -> Weight {
+			let db = T::DbWeight::get();
+			let mut weight = db.reads_writes(1, 1);
+
+			for cui in TransferExpiries::<T>::take(now) {
+				weight = weight.saturating_add(db.reads(1));
+				// Ofertele acceptate, retrase sau înlocuite își eliberează locul, deci oferta ar
+				// trebui să existe; verificările sunt doar o precauție
+				let Some(offer) = TransferOffers::<T>::get(&cui) else { continue };
+				if offer.expires_at >= now {
+					continue
+				}
+				TransferOffers::<T>::remove(&cui);
+				TransfersByRecipient::<T>::remove(&offer.to, &cui);
+				weight = weight.saturating_add(db.writes(2));
+				Self::deposit_event(Event::TransferOfferExpired { cui, to: offer.to });
+			}
+
+			weight
+		}
+
+		/// Ofertele de transfer neexpirate primite de `who`.
+		pub fn pending_transfers(who: &T::AccountId) -> Vec<(T::CUI, TransferOffer<T>)> {
+			let now = <frame_system::Pallet<T>>::block_number();
+			TransfersByRecipient::<T>::iter_key_prefix(who)
+				.filter_map(|cui| TransferOffers::<T>::get(&cui).map(|offer| (cui, offer)))
+				.filter(|(_, offer)| offer.expires_at >= now)
+				.collect()
+		}
 	}
 }
\ No newline at end of file
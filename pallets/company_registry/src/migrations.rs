@@ -0,0 +1,250 @@
+//! Migrările stocării registrului de firme.
+
+use crate::pallet::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, sp_runtime::traits::Saturating,
+	storage_alias, traits::OnRuntimeUpgrade,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+#[cfg(feature = "try-runtime")]
+use frame_support::sp_runtime::TryRuntimeError;
+
+/// Versiunea 1: prima versiune declarată. Codificarea `Company` nu s-a schimbat, deci migrarea
+/// doar fixează versiunea; sub `try-runtime` verifică faptul că toate intrările se decodează
+/// cu tipurile mărginite din runtime.
+pub mod v1 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateToV1<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			Weight::zero()
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let companies = Companies::<T>::iter_keys().count() as u32;
+			let queries = PaidQueries::<T>::iter_keys().count() as u32;
+			Ok((companies, queries).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (companies, queries) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+
+			// `iter` sare peste intrările care nu se pot decoda
+			ensure!(
+				Companies::<T>::iter().count() as u32 == companies,
+				"undecodable companies in storage"
+			);
+			ensure!(
+				PaidQueries::<T>::iter().count() as u32 == queries,
+				"undecodable paid queries in storage"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateToV1<T> = VersionedMigration<
+		0,
+		1,
+		VersionUncheckedMigrateToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Versiunea 2: CUI-ul trece de la `u16` la `T::CUI` (`u64` în runtime). Cheile hașurate se
+/// schimbă odată cu tipul, deci `Companies` și `PaidQueries` sunt rescrise. Cifra de control nu
+/// se verifică la migrare; firmele existente rămân chiar dacă CUI-ul lor nu este valid.
+pub mod v2 {
+	use super::*;
+
+	/// Structurile din versiunea 1, cu CUI-ul pe 16 biți.
+	pub(crate) mod v1 {
+		use super::*;
+
+		#[derive(Encode, Decode)]
+		pub struct OldCompany<T: Config> {
+			pub cui: u16,
+			pub denumire: T::Denumire,
+			pub cod_inmatriculare: T::CodInmatriculare,
+			pub euid: T::EUID,
+			pub stare_firma: T::StareFirma,
+			pub adresa_completa: T::AdresaCompleta,
+			pub owner: Option<T::AccountId>,
+		}
+
+		#[storage_alias]
+		pub type Companies<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, u16, OldCompany<T>>;
+
+		#[storage_alias]
+		pub type PaidQueries<T: Config> = StorageDoubleMap<
+			Pallet<T>,
+			Blake2_128Concat,
+			<T as frame_system::Config>::AccountId,
+			Blake2_128Concat,
+			u16,
+			BlockNumberFor<T>,
+		>;
+	}
+
+	/// Forma `Company` din versiunea 2, pe care o scrie această migrare. Înlocuiește aici
+	/// tipul curent, care a primit între timp câmpuri noi (vezi `v3`).
+	#[derive(Encode, Decode)]
+	pub struct Company<T: Config> {
+		pub cui: T::CUI,
+		pub denumire: T::Denumire,
+		pub cod_inmatriculare: T::CodInmatriculare,
+		pub euid: T::EUID,
+		pub stare_firma: T::StareFirma,
+		pub adresa_completa: T::AdresaCompleta,
+		pub owner: Option<T::AccountId>,
+	}
+
+	#[storage_alias]
+	pub type Companies<T: Config> =
+		StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::CUI, Company<T>>;
+
+	pub struct VersionUncheckedMigrateToV2<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV2<T> {
+		fn on_runtime_upgrade() -> Weight {
+			// Intrările vechi se citesc toate înainte de scriere: cheile noi au același prefix
+			let companies: Vec<_> = v1::Companies::<T>::drain().collect();
+			let queries: Vec<_> = v1::PaidQueries::<T>::drain().collect();
+			let moved = (companies.len() + queries.len()) as u64;
+
+			let mut invalid: u32 = 0;
+			for (cui, old) in companies {
+				let cui = T::CUI::from(u64::from(cui));
+				if !crate::cui::is_valid(cui.into()) {
+					invalid.saturating_inc();
+				}
+				Companies::<T>::insert(
+					cui,
+					Company {
+						cui,
+						denumire: old.denumire,
+						cod_inmatriculare: old.cod_inmatriculare,
+						euid: old.euid,
+						stare_firma: old.stare_firma,
+						adresa_completa: old.adresa_completa,
+						owner: old.owner,
+					},
+				);
+			}
+			for (caller, cui, block) in queries {
+				PaidQueries::<T>::insert(caller, T::CUI::from(u64::from(cui)), block);
+			}
+
+			log::info!(
+				target: "runtime::company-registry",
+				"re-keyed {} entries, {} companies with an invalid CUI",
+				moved,
+				invalid,
+			);
+			// Fiecare intrare este citită, ștearsă și scrisă din nou
+			T::DbWeight::get().reads_writes(moved, moved.saturating_mul(2))
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			let companies = v1::Companies::<T>::iter_keys().count() as u32;
+			let queries = v1::PaidQueries::<T>::iter_keys().count() as u32;
+			Ok((companies, queries).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let (companies, queries) = <(u32, u32)>::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+
+			ensure!(
+				Companies::<T>::iter().count() as u32 == companies,
+				"some companies were not migrated"
+			);
+			ensure!(
+				PaidQueries::<T>::iter().count() as u32 == queries,
+				"some paid queries were not migrated"
+			);
+			for (cui, company) in Companies::<T>::iter() {
+				ensure!(company.cui == cui, "company stored under another CUI");
+			}
+			Ok(())
+		}
+	}
+
+	pub type MigrateToV2<T> = VersionedMigration<
+		1,
+		2,
+		VersionUncheckedMigrateToV2<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
+
+/// Versiunea 3: firmele primesc județul și registratorul care le-a confirmat datele. Firmele
+/// existente rămân fără județ până când îl completează root prin `set_company_county`; până
+/// atunci pot fi modificate doar de registratorii fără arie limitată.
+pub mod v3 {
+	use super::*;
+
+	pub struct VersionUncheckedMigrateToV3<T>(PhantomData<T>);
+
+	impl<T: Config> OnRuntimeUpgrade for VersionUncheckedMigrateToV3<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let mut translated: u64 = 0;
+			Companies::<T>::translate::<v2::Company<T>, _>(|_, old| {
+				translated.saturating_inc();
+				Some(Company {
+					cui: old.cui,
+					denumire: old.denumire,
+					cod_inmatriculare: old.cod_inmatriculare,
+					euid: old.euid,
+					stare_firma: old.stare_firma,
+					adresa_completa: old.adresa_completa,
+					owner: old.owner,
+					judet: None,
+					attested_by: None,
+				})
+			});
+
+			log::info!(target: "runtime::company-registry", "migrated {} companies to v3", translated);
+			T::DbWeight::get().reads_writes(translated, translated)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+			Ok((v2::Companies::<T>::iter().count() as u32).encode())
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+			let expected = u32::decode(&mut &state[..])
+				.map_err(|_| TryRuntimeError::Other("invalid pre-upgrade state"))?;
+
+			ensure!(
+				Companies::<T>::iter().count() as u32 == expected,
+				"some companies were not migrated"
+			);
+			ensure!(
+				Companies::<T>::iter_keys().count() as u32 == expected,
+				"undecodable companies left in storage"
+			);
+			Ok(())
+		}
+	}
+
+	pub type MigrateToV3<T> = VersionedMigration<
+		2,
+		3,
+		VersionUncheckedMigrateToV3<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
@@ -1,8 +1,10 @@
-use crate as bizix_core;
+use crate as pallet_company_registry;
 use frame_support::{
 	derive_impl,
-	traits::{ConstU16, ConstU64},
+	traits::{ConstU16, ConstU32, ConstU64},
+	BoundedVec,
 };
+use frame_system::EnsureRoot;
 use sp_core::H256;
 use sp_runtime::{
 	traits::{BlakeTwo256, IdentityLookup},
@@ -16,7 +18,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system,
-		TemplateModule: bizix_core,
+		Balances: pallet_balances,
+		CompanyRegistry: pallet_company_registry,
 	}
 );
 
@@ -38,21 +41,72 @@ impl frame_system::Config for Test {
 	type BlockHashCount = ConstU64<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
 	type SS58Prefix = ConstU16<42>;
 	type OnSetCode = ();
-	type MaxConsumers = frame_support::traits::ConstU32<16>;
+	type MaxConsumers = ConstU32<16>;
 }
 
-impl bizix_core::Config for Test {
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
 	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
 	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
+/// Contul 2 are identitatea confirmată ca `REPRESENTATIVE`.
+pub const REPRESENTATIVE: &[u8] = b"Ion Popescu";
+
+pub struct TestIdentity;
+impl pallet_company_registry::IdentityVerifier<u64> for TestIdentity {
+	fn is_verified_as(who: &u64, name: &[u8]) -> bool {
+		*who == 2 && name == REPRESENTATIVE
+	}
+}
+
+impl pallet_company_registry::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type CUI = u64;
+	type Denumire = BoundedVec<u8, ConstU32<128>>;
+	type CodInmatriculare = BoundedVec<u8, ConstU32<32>>;
+	type EUID = BoundedVec<u8, ConstU32<32>>;
+	type StareFirma = BoundedVec<u8, ConstU32<32>>;
+	type AdresaCompleta = BoundedVec<u8, ConstU32<256>>;
+	type Currency = Balances;
+	type QueryFee = ConstU64<1>;
+	type RegistrarOrigin = EnsureRoot<u64>;
+	type RegistrarBond = ConstU64<100>;
+	type Slashed = ();
+	type ClaimDeposit = ConstU64<10>;
+	type ChallengePeriod = ConstU64<5>;
+	type ClaimResolutionPeriod = ConstU64<10>;
+	type Identity = TestIdentity;
+	type TransferOfferPeriod = ConstU64<3>;
+	type MaxTransferExpiriesPerBlock = ConstU32<2>;
+	type MaxRevisions = ConstU32<3>;
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	frame_system::GenesisConfig::<Test>::default().build_storage().unwrap().into()
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 1_000), (2, 1_000), (3, 1_000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
@@ -1,27 +1,729 @@
-use crate::{mock::*, Error, Event, Something};
-use frame_support::{assert_noop, assert_ok};
+use crate::{
+	cui::{self, MAX_CUI},
+	mock::*,
+	Companies, Company, CompanyField, CountyCode, Error, Event, FieldValue, PaidQueries,
+	TransferExpiries, TransferOffers, TransfersByRecipient,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks, OnRuntimeUpgrade, StorageVersion},
+	BoundedVec,
+};
+use sp_runtime::DispatchResult;
+
+// CUI-uri reale, cu cifra de control corectă
+const CUI: u64 = 14399840;
+const OTHER_CUI: u64 = 18547290;
+
+fn text<S: Get<u32>>(value: &[u8]) -> BoundedVec<u8, S> {
+	BoundedVec::truncate_from(value.to_vec())
+}
+
+fn county(code: &[u8]) -> CountyCode {
+	text(code)
+}
+
+fn appoint(who: u64, scope: Option<&[u8]>) {
+	assert_ok!(CompanyRegistry::post_registrar_bond(RuntimeOrigin::signed(who)));
+	assert_ok!(CompanyRegistry::appoint_registrar(RuntimeOrigin::root(), who, scope.map(county)));
+}
+
+fn add_company(registrar: u64, cui: u64, judet: &[u8]) -> DispatchResult {
+	CompanyRegistry::add_company(
+		RuntimeOrigin::signed(registrar),
+		cui,
+		text(b"Firma SRL"),
+		text(b"J40/1/2000"),
+		text(b"ROONRC.J40/1/2000"),
+		text(b"functiune"),
+		text(b"Bucuresti"),
+		county(judet),
+	)
+}
+
+#[test]
+fn cui_check_digit_is_validated() {
+	for valid in [CUI, OTHER_CUI, 27] {
+		assert!(cui::is_valid(valid), "{valid} should be valid");
+	}
+	// Cifra de control greșită
+	for invalid in [14399841, 18547291, 28] {
+		assert!(!cui::is_valid(invalid), "{invalid} has a bad check digit");
+	}
+	// Prea multe cifre
+	assert!(!cui::is_valid(MAX_CUI + 1));
+	assert!(!cui::is_valid(u64::MAX));
+	// Prea puține cifre
+	assert!(!cui::is_valid(0));
+	assert!(!cui::is_valid(7));
+}
+
+#[test]
+fn add_company_rejects_invalid_cui() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+
+		assert_noop!(add_company(1, CUI + 1, b"B"), Error::<Test>::InvalidCui);
+		assert_noop!(add_company(1, MAX_CUI + 1, b"B"), Error::<Test>::InvalidCui);
+
+		assert_ok!(add_company(1, CUI, b"B"));
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().cui, CUI);
+		System::assert_last_event(Event::CompanyAdded { cui: CUI, sender: 1 }.into());
+		assert_noop!(add_company(1, CUI, b"B"), Error::<Test>::CompanyAlreadyExists);
+	});
+}
+
+#[test]
+fn migration_to_v2_rekeys_companies_and_queries() {
+	use crate::migrations::v2::{self, v1, MigrateToV2};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(1).put::<CompanyRegistry>();
+		let old = |cui: u16| v1::OldCompany::<Test> {
+			cui,
+			denumire: text(b"Firma SRL"),
+			cod_inmatriculare: text(b"J40/1/2000"),
+			euid: text(b"ROONRC.J40/1/2000"),
+			stare_firma: text(b"functiune"),
+			adresa_completa: text(b"Bucuresti"),
+			owner: Some(2),
+		};
+		v1::Companies::<Test>::insert(27, old(27));
+		// Firmele cu CUI invalid rămân în registru
+		v1::Companies::<Test>::insert(28, old(28));
+		v1::PaidQueries::<Test>::insert(3, 27, 5);
+
+		MigrateToV2::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<CompanyRegistry>(), 2);
+		assert_eq!(v1::Companies::<Test>::iter_keys().count(), 0);
+		assert_eq!(v1::PaidQueries::<Test>::iter_keys().count(), 0);
+
+		for cui in [27u64, 28] {
+			let company = v2::Companies::<Test>::get(cui).unwrap();
+			assert_eq!(company.cui, cui);
+			assert_eq!(company.owner, Some(2));
+			assert_eq!(company.denumire.to_vec(), b"Firma SRL".to_vec());
+		}
+		assert_eq!(PaidQueries::<Test>::get(3, 27), Some(5));
+	});
+}
+
+fn update_denumire(registrar: u64, cui: u64, denumire: &[u8]) -> DispatchResult {
+	CompanyRegistry::update_company(
+		RuntimeOrigin::signed(registrar),
+		cui,
+		Some(text(denumire)),
+		None,
+		None,
+		None,
+		None,
+		None,
+	)
+}
+
+#[test]
+fn registrars_post_their_own_bond() {
+	new_test_ext().execute_with(|| {
+		// Fără garanția depusă de candidat numirea eșuează
+		assert_noop!(
+			CompanyRegistry::appoint_registrar(RuntimeOrigin::root(), 1, None),
+			Error::<Test>::NoBondPosted
+		);
+		assert_noop!(
+			CompanyRegistry::post_registrar_bond(RuntimeOrigin::signed(42)),
+			Error::<Test>::InsufficientBond
+		);
+
+		assert_ok!(CompanyRegistry::post_registrar_bond(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_noop!(
+			CompanyRegistry::post_registrar_bond(RuntimeOrigin::signed(1)),
+			Error::<Test>::BondAlreadyPosted
+		);
+		assert_noop!(
+			CompanyRegistry::appoint_registrar(RuntimeOrigin::signed(1), 1, None),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(CompanyRegistry::appoint_registrar(
+			RuntimeOrigin::root(),
+			1,
+			Some(county(b"CJ"))
+		));
+		System::assert_last_event(
+			Event::RegistrarAppointed { who: 1, scope: Some(county(b"CJ")), bond: 100 }.into(),
+		);
+		assert!(CompanyRegistry::registrar_candidates(1).is_none());
+		assert_noop!(
+			CompanyRegistry::post_registrar_bond(RuntimeOrigin::signed(1)),
+			Error::<Test>::AlreadyRegistrar
+		);
+
+		// Un candidat nenumit își poate retrage garanția
+		assert_ok!(CompanyRegistry::post_registrar_bond(RuntimeOrigin::signed(2)));
+		assert_ok!(CompanyRegistry::withdraw_registrar_bond(RuntimeOrigin::signed(2)));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(
+			CompanyRegistry::withdraw_registrar_bond(RuntimeOrigin::signed(2)),
+			Error::<Test>::NoBondPosted
+		);
+
+		// Revocarea eliberează sau confiscă garanția
+		appoint(2, None);
+		assert_ok!(CompanyRegistry::remove_registrar(RuntimeOrigin::root(), 1, false));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 1_000);
+		assert_ok!(CompanyRegistry::remove_registrar(RuntimeOrigin::root(), 2, true));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 900);
+		System::assert_last_event(Event::RegistrarRemoved { who: 2, slashed: 100 }.into());
+	});
+}
+
+#[test]
+fn registrars_only_write_in_their_county() {
+	new_test_ext().execute_with(|| {
+		appoint(1, Some(b"CJ"));
+		appoint(2, None);
+
+		assert_noop!(add_company(3, CUI, b"CJ"), Error::<Test>::NotRegistrar);
+		assert_noop!(add_company(1, CUI, b"B"), Error::<Test>::OutOfScope);
+		assert_ok!(add_company(1, CUI, b"CJ"));
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().attested_by, Some(1));
+
+		// Registratorul național scrie oriunde și devine ultimul care a confirmat datele
+		assert_ok!(add_company(2, OTHER_CUI, b"B"));
+		assert_ok!(update_denumire(2, CUI, b"Alta SRL"));
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().attested_by, Some(2));
+
+		assert_noop!(update_denumire(1, OTHER_CUI, b"Alta SRL"), Error::<Test>::OutOfScope);
+		assert_noop!(update_denumire(3, CUI, b"Alta SRL"), Error::<Test>::NotRegistrar);
+
+		// Mutarea sediului cere drepturi și în județul nou
+		assert_noop!(
+			CompanyRegistry::update_company(
+				RuntimeOrigin::signed(1),
+				CUI,
+				None,
+				None,
+				None,
+				None,
+				None,
+				Some(county(b"B"))
+			),
+			Error::<Test>::OutOfScope
+		);
+	});
+}
+
+#[test]
+fn companies_without_county_are_backfilled_by_root() {
+	new_test_ext().execute_with(|| {
+		appoint(1, Some(b"CJ"));
+		// O firmă migrată la versiunea 3, fără județ
+		Companies::<Test>::insert(
+			CUI,
+			Company {
+				cui: CUI,
+				denumire: text(b"Firma SRL"),
+				cod_inmatriculare: text(b"J12/1/2000"),
+				euid: text(b"ROONRC.J12/1/2000"),
+				stare_firma: text(b"functiune"),
+				adresa_completa: text(b"Cluj-Napoca"),
+				owner: None,
+				judet: None,
+				attested_by: None,
+			},
+		);
+		assert_noop!(update_denumire(1, CUI, b"Alta SRL"), Error::<Test>::OutOfScope);
+
+		assert_noop!(
+			CompanyRegistry::set_company_county(RuntimeOrigin::signed(1), CUI, county(b"CJ")),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CompanyRegistry::set_company_county(RuntimeOrigin::root(), OTHER_CUI, county(b"CJ")),
+			Error::<Test>::CompanyNotFound
+		);
+		assert_ok!(CompanyRegistry::set_company_county(RuntimeOrigin::root(), CUI, county(b"CJ")));
+		System::assert_last_event(
+			Event::CompanyCountySet { cui: CUI, judet: county(b"CJ") }.into(),
+		);
+
+		// Schimbarea apare în istoric, fără autor
+		let last = CompanyRegistry::company_history(CUI).last().cloned().unwrap();
+		assert_eq!(last.author, None);
+		assert_eq!(last.changes.len(), 1);
+		assert_eq!(last.changes[0].old, None);
+		assert_eq!(last.changes[0].new, FieldValue::Judet(county(b"CJ")));
+
+		// Județul completat nu mai poate fi suprascris de `RegistrarOrigin`
+		assert_noop!(
+			CompanyRegistry::set_company_county(RuntimeOrigin::root(), CUI, county(b"B")),
+			Error::<Test>::CountyAlreadySet
+		);
+		assert_ok!(update_denumire(1, CUI, b"Alta SRL"));
+	});
+}
+
+#[test]
+fn migration_to_v3_adds_county_fields() {
+	use crate::migrations::{v2, v3::MigrateToV3};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(2).put::<CompanyRegistry>();
+		v2::Companies::<Test>::insert(
+			CUI,
+			v2::Company::<Test> {
+				cui: CUI,
+				denumire: text(b"Firma SRL"),
+				cod_inmatriculare: text(b"J40/1/2000"),
+				euid: text(b"ROONRC.J40/1/2000"),
+				stare_firma: text(b"functiune"),
+				adresa_completa: text(b"Bucuresti"),
+				owner: Some(2),
+			},
+		);
+
+		MigrateToV3::<Test>::on_runtime_upgrade();
+
+		assert_eq!(StorageVersion::get::<CompanyRegistry>(), 3);
+		let company = CompanyRegistry::companies(CUI).unwrap();
+		assert_eq!(company.owner, Some(2));
+		assert_eq!(company.judet, None);
+		assert_eq!(company.attested_by, None);
+	});
+}
+
+/// Registratorul 1 adaugă firma `CUI`, pe care contul 2 o revendică.
+fn claimed_company() {
+	appoint(1, None);
+	assert_ok!(add_company(1, CUI, b"B"));
+	assert_ok!(CompanyRegistry::claim_company(RuntimeOrigin::signed(2), CUI));
+}
+
+#[test]
+fn unchallenged_claims_become_final_after_the_challenge_period() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		assert_noop!(
+			CompanyRegistry::claim_company(RuntimeOrigin::signed(2), CUI),
+			Error::<Test>::CompanyNotFound
+		);
+		assert_ok!(add_company(1, CUI, b"B"));
+		assert_noop!(
+			CompanyRegistry::claim_company(RuntimeOrigin::signed(42), CUI),
+			Error::<Test>::InsufficientDeposit
+		);
+
+		assert_ok!(CompanyRegistry::claim_company(RuntimeOrigin::signed(2), CUI));
+		System::assert_last_event(
+			Event::ClaimSubmitted { cui: CUI, claimant: 2, challenge_end: 6 }.into(),
+		);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert_noop!(
+			CompanyRegistry::claim_company(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::ClaimPending
+		);
+
+		System::set_block_number(6);
+		assert_noop!(
+			CompanyRegistry::finalize_claim(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::ChallengePeriodActive
+		);
+
+		System::set_block_number(7);
+		assert_ok!(CompanyRegistry::finalize_claim(RuntimeOrigin::signed(3), CUI));
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, Some(2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(CompanyRegistry::pending_claims(CUI).is_none());
+		System::assert_last_event(Event::CompanyClaimed { cui: CUI, owner: 2 }.into());
+
+		assert_noop!(
+			CompanyRegistry::claim_company(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::CompanyAlreadyClaimed
+		);
+	});
+}
+
+#[test]
+fn approving_a_challenged_claim_slashes_the_challenger() {
+	new_test_ext().execute_with(|| {
+		claimed_company();
+
+		assert_noop!(
+			CompanyRegistry::challenge_claim(RuntimeOrigin::signed(2), CUI),
+			Error::<Test>::CannotChallengeOwnClaim
+		);
+		assert_ok!(CompanyRegistry::challenge_claim(RuntimeOrigin::signed(3), CUI));
+		System::assert_last_event(Event::ClaimChallenged { cui: CUI, challenger: 3 }.into());
+		assert_eq!(Balances::reserved_balance(3), 10);
+		assert_noop!(
+			CompanyRegistry::challenge_claim(RuntimeOrigin::signed(1), CUI),
+			Error::<Test>::AlreadyChallenged
+		);
+
+		// O revendicare contestată nu devine definitivă la sfârșitul perioadei de contestare
+		System::set_block_number(7);
+		assert_noop!(
+			CompanyRegistry::finalize_claim(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::ClaimDisputed
+		);
+
+		assert_noop!(
+			CompanyRegistry::approve_claim(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::NotRegistrar
+		);
+		assert_ok!(CompanyRegistry::approve_claim(RuntimeOrigin::signed(1), CUI));
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, Some(2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 1_000);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 990);
+	});
+}
+
+#[test]
+fn rejecting_a_fraudulent_claim_slashes_the_claimant() {
+	new_test_ext().execute_with(|| {
+		claimed_company();
+		assert_ok!(CompanyRegistry::challenge_claim(RuntimeOrigin::signed(3), CUI));
+
+		assert_ok!(CompanyRegistry::reject_claim(RuntimeOrigin::signed(1), CUI));
+		System::assert_last_event(
+			Event::ClaimRejected { cui: CUI, claimant: 2, slashed: 10 }.into(),
+		);
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, None);
+		assert_eq!(Balances::free_balance(2), 990);
+		assert_eq!(Balances::reserved_balance(2), 0);
+		// Contestatarul își primește depozitul înapoi
+		assert_eq!(Balances::free_balance(3), 1_000);
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_noop!(
+			CompanyRegistry::reject_claim(RuntimeOrigin::signed(1), CUI),
+			Error::<Test>::NoPendingClaim
+		);
+	});
+}
+
+#[test]
+fn claims_cannot_be_challenged_after_the_challenge_period() {
+	new_test_ext().execute_with(|| {
+		claimed_company();
+
+		System::set_block_number(7);
+		assert_noop!(
+			CompanyRegistry::challenge_claim(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::ChallengePeriodOver
+		);
+	});
+}
+
+#[test]
+fn unresolved_disputes_expire_and_release_both_deposits() {
+	new_test_ext().execute_with(|| {
+		claimed_company();
+		assert_ok!(CompanyRegistry::challenge_claim(RuntimeOrigin::signed(3), CUI));
+
+		// Registratorii au timp până la blocul 16
+		System::set_block_number(16);
+		assert_noop!(
+			CompanyRegistry::finalize_claim(RuntimeOrigin::signed(1), CUI),
+			Error::<Test>::ClaimDisputed
+		);
+
+		System::set_block_number(17);
+		assert_ok!(CompanyRegistry::finalize_claim(RuntimeOrigin::signed(1), CUI));
+		System::assert_last_event(Event::ClaimExpired { cui: CUI, claimant: 2 }.into());
+		assert!(CompanyRegistry::pending_claims(CUI).is_none());
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, None);
+		for who in [2, 3] {
+			assert_eq!(Balances::reserved_balance(who), 0);
+			assert_eq!(Balances::free_balance(who), 1_000);
+		}
+	});
+}
+
+#[test]
+fn claims_can_require_the_legal_representative_identity() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		assert_ok!(add_company(1, CUI, b"B"));
+		assert_ok!(CompanyRegistry::set_identity_required(RuntimeOrigin::root(), true));
+
+		assert_noop!(
+			CompanyRegistry::claim_company(RuntimeOrigin::signed(2), CUI),
+			Error::<Test>::MissingRepresentative
+		);
+		assert_ok!(CompanyRegistry::set_legal_representative(
+			RuntimeOrigin::signed(1),
+			CUI,
+			text(REPRESENTATIVE)
+		));
+		assert_noop!(
+			CompanyRegistry::claim_company(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::IdentityNotVerified
+		);
+		assert_ok!(CompanyRegistry::claim_company(RuntimeOrigin::signed(2), CUI));
+	});
+}
+
+/// Firma `cui`, adăugată de registratorul 1 și deținută de `owner`.
+fn owned_company(cui: u64, owner: u64) {
+	assert_ok!(add_company(1, cui, b"B"));
+	Companies::<Test>::mutate(cui, |company| company.as_mut().unwrap().owner = Some(owner));
+}
+
+fn offer(from: u64, cui: u64, to: u64) -> DispatchResult {
+	CompanyRegistry::offer_company_transfer(RuntimeOrigin::signed(from), cui, to)
+}
 
 #[test]
-fn it_works_for_default_value() {
+fn transfers_must_be_accepted_by_the_recipient() {
 	new_test_ext().execute_with(|| {
-		// Go past genesis block so events get deposited
-		System::set_block_number(1);
-		// Dispatch a signed extrinsic.
-		assert_ok!(TemplateModule::do_something(RuntimeOrigin::signed(1), 42));
-		// Read pallet storage and assert an expected result.
-		assert_eq!(Something::<Test>::get(), Some(42));
-		// Assert that the correct event was deposited
-		System::assert_last_event(Event::SomethingStored { something: 42, who: 1 }.into());
+		appoint(1, None);
+		owned_company(CUI, 2);
+
+		assert_noop!(offer(3, CUI, 3), Error::<Test>::NotCompanyOwner);
+		assert_noop!(offer(2, CUI, 2), Error::<Test>::CannotTransferToSelf);
+		assert_noop!(offer(2, OTHER_CUI, 3), Error::<Test>::CompanyNotFound);
+
+		assert_ok!(offer(2, CUI, 3));
+		System::assert_last_event(
+			Event::TransferOffered { cui: CUI, from: 2, to: 3, expires_at: 4 }.into(),
+		);
+		assert_eq!(CompanyRegistry::pending_transfers(&3).len(), 1);
+		// Oferta nu schimbă proprietarul până la acceptare
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, Some(2));
+
+		assert_noop!(
+			CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(1), CUI),
+			Error::<Test>::NotTransferRecipient
+		);
+		assert_ok!(CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(3), CUI));
+		System::assert_last_event(
+			Event::CompanyOwnershipTransferred { cui: CUI, previous_owner: 2, new_owner: 3 }.into(),
+		);
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, Some(3));
+		assert!(TransferOffers::<Test>::get(CUI).is_none());
+		assert!(!TransfersByRecipient::<Test>::contains_key(3, CUI));
+		assert_noop!(
+			CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::NoTransferOffer
+		);
+	});
+}
+
+#[test]
+fn transfers_can_be_cancelled_or_replaced() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		owned_company(CUI, 2);
+
+		assert_ok!(offer(2, CUI, 3));
+		// O ofertă nouă o înlocuiește pe cea veche
+		assert_ok!(offer(2, CUI, 1));
+		assert!(!TransfersByRecipient::<Test>::contains_key(3, CUI));
+		assert!(CompanyRegistry::pending_transfers(&3).is_empty());
+		assert_noop!(
+			CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::NotTransferRecipient
+		);
+
+		assert_noop!(
+			CompanyRegistry::cancel_company_transfer(RuntimeOrigin::signed(1), CUI),
+			Error::<Test>::NotCompanyOwner
+		);
+		assert_ok!(CompanyRegistry::cancel_company_transfer(RuntimeOrigin::signed(2), CUI));
+		System::assert_last_event(Event::TransferCancelled { cui: CUI, to: 1 }.into());
+		assert!(TransferOffers::<Test>::get(CUI).is_none());
+		assert!(!TransfersByRecipient::<Test>::contains_key(1, CUI));
+		assert_noop!(
+			CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(1), CUI),
+			Error::<Test>::NoTransferOffer
+		);
 	});
 }
 
 #[test]
-fn correct_error_for_none_value() {
+fn expired_transfers_are_pruned() {
 	new_test_ext().execute_with(|| {
-		// Ensure the expected error is thrown when no value is present.
+		appoint(1, None);
+		owned_company(CUI, 2);
+		assert_ok!(offer(2, CUI, 3));
+		assert_eq!(TransferExpiries::<Test>::get(5).to_vec(), vec![CUI]);
+
+		System::set_block_number(4);
+		CompanyRegistry::on_initialize(4);
+		assert!(TransferOffers::<Test>::get(CUI).is_some());
+
+		System::set_block_number(5);
 		assert_noop!(
-			TemplateModule::cause_error(RuntimeOrigin::signed(1)),
-			Error::<Test>::NoneValue
+			CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(3), CUI),
+			Error::<Test>::TransferExpired
+		);
+		assert!(CompanyRegistry::pending_transfers(&3).is_empty());
+
+		CompanyRegistry::on_initialize(5);
+		System::assert_last_event(Event::TransferOfferExpired { cui: CUI, to: 3 }.into());
+		assert!(TransferOffers::<Test>::get(CUI).is_none());
+		assert!(!TransfersByRecipient::<Test>::contains_key(3, CUI));
+		assert!(TransferExpiries::<Test>::get(5).is_empty());
+		assert_eq!(CompanyRegistry::companies(CUI).unwrap().owner, Some(2));
+	});
+}
+
+#[test]
+fn replaced_offers_are_not_pruned_early() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		owned_company(CUI, 2);
+		assert_ok!(offer(2, CUI, 3));
+
+		System::set_block_number(3);
+		assert_ok!(offer(2, CUI, 1));
+		System::assert_has_event(Event::TransferCancelled { cui: CUI, to: 3 }.into());
+		assert!(TransferExpiries::<Test>::get(5).is_empty());
+
+		// Ștergerea programată pentru prima ofertă o lasă în pace pe a doua
+		System::set_block_number(5);
+		CompanyRegistry::on_initialize(5);
+		assert_eq!(TransferOffers::<Test>::get(CUI).unwrap().to, 1);
+
+		System::set_block_number(7);
+		CompanyRegistry::on_initialize(7);
+		assert!(TransferOffers::<Test>::get(CUI).is_none());
+		assert!(!TransfersByRecipient::<Test>::contains_key(1, CUI));
+	});
+}
+
+#[test]
+fn re_offering_does_not_take_more_expiry_slots() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		owned_company(CUI, 2);
+		for to in [3, 1, 3, 1, 3, 1] {
+			assert_ok!(offer(2, CUI, to));
+		}
+
+		// Doar ultima ofertă ocupă un loc, deși `MaxTransferExpiriesPerBlock` este 2
+		let slots: usize = TransferExpiries::<Test>::iter_values().map(|cuis| cuis.len()).sum();
+		assert_eq!(slots, 1);
+		assert_eq!(TransferExpiries::<Test>::get(5).to_vec(), vec![CUI]);
+		System::assert_has_event(Event::TransferCancelled { cui: CUI, to: 3 }.into());
+		assert!(!TransfersByRecipient::<Test>::contains_key(3, CUI));
+
+		// Retragerea eliberează și ea locul
+		assert_ok!(CompanyRegistry::cancel_company_transfer(RuntimeOrigin::signed(2), CUI));
+		assert_eq!(TransferExpiries::<Test>::iter_values().count(), 0);
+	});
+}
+
+#[test]
+fn expiries_move_to_the_next_block_when_full() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		for cui in [CUI, OTHER_CUI, 27] {
+			owned_company(cui, 2);
+			assert_ok!(offer(2, cui, 3));
+		}
+
+		assert_eq!(TransferExpiries::<Test>::get(5).to_vec(), vec![CUI, OTHER_CUI]);
+		assert_eq!(TransferExpiries::<Test>::get(6).to_vec(), vec![27]);
+
+		System::set_block_number(5);
+		CompanyRegistry::on_initialize(5);
+		System::set_block_number(6);
+		CompanyRegistry::on_initialize(6);
+		assert!(CompanyRegistry::pending_transfers(&3).is_empty());
+		assert_eq!(TransfersByRecipient::<Test>::iter_prefix(3).count(), 0);
+	});
+}
+
+#[test]
+fn history_records_only_changed_fields() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		assert_ok!(add_company(1, CUI, b"B"));
+
+		let history = CompanyRegistry::company_history(CUI);
+		assert_eq!(history.len(), 1);
+		assert_eq!(history[0].author, Some(1));
+		assert_eq!(history[0].changes.len(), 6);
+		assert!(history[0].changes.iter().all(|change| change.old.is_none()));
+
+		// Aceeași denumire nu produce o revizie nouă
+		assert_ok!(update_denumire(1, CUI, b"Firma SRL"));
+		assert_eq!(CompanyRegistry::company_history(CUI).len(), 1);
+
+		System::set_block_number(2);
+		assert_ok!(update_denumire(1, CUI, b"Firma Noua SRL"));
+		let history = CompanyRegistry::company_history(CUI);
+		assert_eq!(history.len(), 2);
+		assert_eq!(history[1].block, 2);
+		assert_eq!(history[1].changes.len(), 1);
+		assert_eq!(history[1].changes[0].new.field(), CompanyField::Denumire);
+		assert_eq!(history[1].changes[0].old, Some(FieldValue::Denumire(text(b"Firma SRL"))));
+		assert_eq!(history[1].changes[0].new, FieldValue::Denumire(text(b"Firma Noua SRL")));
+	});
+}
+
+#[test]
+fn history_evicts_the_oldest_revision_when_full() {
+	new_test_ext().execute_with(|| {
+		appoint(1, None);
+		assert_ok!(add_company(1, CUI, b"B"));
+		for denumire in [b"Firma A SRL", b"Firma B SRL", b"Firma C SRL"] {
+			assert_ok!(update_denumire(1, CUI, denumire));
+		}
+
+		// `MaxRevisions` este 3, deci revizia de la creare a fost ștearsă
+		let history = CompanyRegistry::company_history(CUI);
+		assert_eq!(history.len(), 3);
+		let names: Vec<_> =
+			history.iter().map(|revision| revision.changes[0].new.clone()).collect();
+		assert_eq!(
+			names,
+			vec![
+				FieldValue::Denumire(text(b"Firma A SRL")),
+				FieldValue::Denumire(text(b"Firma B SRL")),
+				FieldValue::Denumire(text(b"Firma C SRL")),
+			]
 		);
+		assert_eq!(history[0].changes[0].old, Some(FieldValue::Denumire(text(b"Firma SRL"))));
+	});
+}
+
+#[test]
+fn history_records_owner_changes() {
+	new_test_ext().execute_with(|| {
+		claimed_company();
+		System::set_block_number(7);
+		assert_ok!(CompanyRegistry::finalize_claim(RuntimeOrigin::signed(3), CUI));
+
+		let last = CompanyRegistry::company_history(CUI).last().cloned().unwrap();
+		assert_eq!(last.author, Some(2));
+		assert_eq!(last.changes.len(), 1);
+		assert_eq!(last.changes[0].old, None);
+		assert_eq!(last.changes[0].new, FieldValue::Owner(2));
+
+		assert_ok!(offer(2, CUI, 3));
+		assert_ok!(CompanyRegistry::accept_company_transfer(RuntimeOrigin::signed(3), CUI));
+		let last = CompanyRegistry::company_history(CUI).last().cloned().unwrap();
+		assert_eq!(last.author, Some(3));
+		assert_eq!(last.changes[0].old, Some(FieldValue::Owner(2)));
+		assert_eq!(last.changes[0].new, FieldValue::Owner(3));
+	});
+}
+
+#[test]
+fn approved_claims_are_attributed_to_the_registrar() {
+	new_test_ext().execute_with(|| {
+		claimed_company();
+		assert_ok!(CompanyRegistry::approve_claim(RuntimeOrigin::signed(1), CUI));
+
+		let last = CompanyRegistry::company_history(CUI).last().cloned().unwrap();
+		assert_eq!(last.author, Some(1));
+		assert_eq!(last.changes[0].new, FieldValue::Owner(2));
 	});
 }
@@ -0,0 +1,787 @@
+//! # Deployments Pallet
+//!
+//! Lets customers request instances of applications approved in `bizix_core`. A request records
+//! the Proxmox template of the approved proposal together with the requested resources and then
+//! moves through `Requested` → `Provisioning` → `Running` → `Stopped`/`Failed` as the provider
+//! reports progress. Requests are accepted by providers registered in `pallet_providers` that
+//! support the template and have enough capacity.
+//!
+//! Running instances are billed at the end of every `BillingPeriod` from funds the customer
+//! reserved with `top_up`. When the funds run out the instance keeps running for `GracePeriod`
+//! blocks and is then suspended until the customer tops up and resumes it.
+//!
+//! Customers who ran an instance of an application version can rate it from 1 to 5, optionally
+//! with a review published on IPFS. Every application keeps a running total of its ratings so
+//! the catalog can be sorted by reputation.
+//!
+//! Run `cargo doc --package pallet-deployments --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use bizix_core::BalanceOf;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{BalanceStatus, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	pub use pallet_providers::Resources;
+	use sp_runtime::{
+		traits::{One, Saturating, Zero},
+		Perbill,
+	};
+	use sp_std::vec::Vec;
+
+	type CurrencyOf<T> = <T as bizix_core::Config>::Currency;
+
+	/// În câte blocuri consecutive se caută loc în coada de facturare.
+	pub const MAX_BILLING_SCHEDULE_BLOCKS: u32 = 100;
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ bizix_core::Config
+		+ pallet_providers::Config<TemplateId = <Self as bizix_core::Config>::ProxmoxTemplateID>
+	{
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		// Prețul pe perioadă de facturare pentru fiecare unitate de resursă
+		#[pallet::constant]
+		type PricePerVcpu: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type PricePerGbRam: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type PricePerGbDisk: Get<BalanceOf<Self>>;
+		// La câte blocuri se facturează o instanță pornită
+		#[pallet::constant]
+		type BillingPeriod: Get<BlockNumberFor<Self>>;
+		// Cât timp mai rulează o instanță neplătită înainte de suspendare
+		#[pallet::constant]
+		type GracePeriod: Get<BlockNumberFor<Self>>;
+		// Numărul maxim de instanțe facturate într-un bloc; restul se programează mai târziu
+		#[pallet::constant]
+		type MaxBillingsPerBlock: Get<u32>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum DeploymentStatus {
+		// Cerută de client, în așteptarea unui furnizor
+		Requested,
+		// Preluată de un furnizor, mașina virtuală se creează
+		Provisioning,
+		Running,
+		// Suspendată automat pentru neplată
+		Suspended,
+		Stopped,
+		Failed,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Deployment<T: Config> {
+		pub customer: T::AccountId,
+		pub proposal_id: u32,
+		pub template_id: T::ProxmoxTemplateID,
+		pub resources: Resources,
+		pub provider: Option<T::AccountId>,
+		pub status: DeploymentStatus,
+		pub requested_at: BlockNumberFor<T>,
+		pub updated_at: BlockNumberFor<T>,
+		// Instanța a fost facturată până la acest bloc
+		pub billed_until: BlockNumberFor<T>,
+		// Setat când plata a eșuat; la expirare instanța se suspendă
+		pub grace_until: Option<BlockNumberFor<T>>,
+	}
+
+	/// Nota maximă; nota minimă este 1.
+	pub const MAX_RATING: u8 = 5;
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Rating<T: Config> {
+		pub score: u8,
+		// CID-ul unei recenzii publicate pe IPFS
+		pub review: Option<T::IPFSAddress>,
+		pub rated_at: BlockNumberFor<T>,
+	}
+
+	#[derive(
+		Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct RatingSummary {
+		pub count: u32,
+		// Suma notelor; media este `total / count`
+		pub total: u64,
+	}
+
+	// Stocare
+	#[pallet::storage]
+	#[pallet::getter(fn next_deployment_id)]
+	pub type NextDeploymentId<T> = StorageValue<_, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn deployments)]
+	pub type Deployments<T: Config> = StorageMap<_, Blake2_128Concat, u32, Deployment<T>>;
+
+	// Instalările fiecărui client
+	#[pallet::storage]
+	pub type DeploymentsByCustomer<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u32, ()>;
+
+	// Preț fix pe perioadă pentru un template, adăugat peste prețul resurselor
+	#[pallet::storage]
+	#[pallet::getter(fn template_prices)]
+	pub type TemplatePrices<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ProxmoxTemplateID, BalanceOf<T>>;
+
+	// Fondurile rezervate de fiecare client pentru plata instanțelor
+	#[pallet::storage]
+	#[pallet::getter(fn billing_balance)]
+	pub type BillingBalance<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+	// Instanțele de facturat la fiecare bloc, cel mult `MaxBillingsPerBlock`
+	#[pallet::storage]
+	pub type BillingQueue<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		BlockNumberFor<T>,
+		BoundedVec<u32, T::MaxBillingsPerBlock>,
+		ValueQuery,
+	>;
+
+	// Versiunile (propunerile) pentru care clientul a avut cel puțin o instanță pornită
+	#[pallet::storage]
+	pub type RanVersions<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u32, ()>;
+
+	// Nota fiecărui client pentru o versiune
+	#[pallet::storage]
+	#[pallet::getter(fn ratings)]
+	pub type Ratings<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, u32, Blake2_128Concat, T::AccountId, Rating<T>>;
+
+	// Totalul notelor pentru toate versiunile unei aplicații
+	#[pallet::storage]
+	#[pallet::getter(fn rating_summaries)]
+	pub type RatingSummaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ApplicationName, RatingSummary, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		DeploymentRequested {
+			deployment_id: u32,
+			customer: T::AccountId,
+			proposal_id: u32,
+			template_id: T::ProxmoxTemplateID,
+			resources: Resources,
+		},
+		DeploymentAccepted {
+			deployment_id: u32,
+			provider: T::AccountId,
+		},
+		DeploymentStatusChanged {
+			deployment_id: u32,
+			from: DeploymentStatus,
+			to: DeploymentStatus,
+		},
+		// O perioadă de funcționare a fost plătită furnizorului
+		DeploymentBilled {
+			deployment_id: u32,
+			customer: T::AccountId,
+			provider: T::AccountId,
+			template_id: T::ProxmoxTemplateID,
+			resources: Resources,
+			period_start: BlockNumberFor<T>,
+			period_end: BlockNumberFor<T>,
+			amount: BalanceOf<T>,
+		},
+		// Furnizorul nu este activ, perioada nu se plătește
+		BillingSkipped {
+			deployment_id: u32,
+			provider: T::AccountId,
+			period_start: BlockNumberFor<T>,
+			period_end: BlockNumberFor<T>,
+		},
+		PaymentOverdue {
+			deployment_id: u32,
+			customer: T::AccountId,
+			amount_due: BalanceOf<T>,
+			grace_until: BlockNumberFor<T>,
+		},
+		DeploymentSuspended {
+			deployment_id: u32,
+			customer: T::AccountId,
+			amount_due: BalanceOf<T>,
+		},
+		BillingFundsAdded {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		BillingFundsWithdrawn {
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+		},
+		TemplatePriceSet {
+			template_id: T::ProxmoxTemplateID,
+			price: Option<BalanceOf<T>>,
+		},
+		ApplicationRated {
+			proposal_id: u32,
+			who: T::AccountId,
+			score: u8,
+			review: Option<T::IPFSAddress>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		ProposalNotFound,
+		// Propunerea nu este aprobată (sau a fost retrasă între timp)
+		ProposalNotDeployable,
+		// Toate resursele trebuie să fie nenule
+		InvalidResources,
+		DeploymentNotFound,
+		NotCustomer,
+		NotAssignedProvider,
+		// Furnizorul nu este activ, nu suportă template-ul sau nu are capacitate suficientă
+		NotEligibleProvider,
+		InvalidStatusTransition,
+		InsufficientFunds,
+		// Fondurile rezervate pentru facturare nu acoperă operația
+		InsufficientBillingBalance,
+		// Doar clienții care au rulat o instanță a versiunii o pot nota
+		NeverRan,
+		// Nota trebuie să fie între 1 și `MAX_RATING`
+		InvalidRating,
+		// Nu mai există loc în coada de facturare în blocurile următoare
+		BillingQueueFull,
+	}
+
+	// Funcții apelabile
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(10_000)]
+		pub fn request_deployment(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			resources: Resources,
+		) -> DispatchResult {
+			let customer = ensure_signed(origin)?;
+			ensure!(!resources.is_empty(), Error::<T>::InvalidResources);
+
+			let proposal =
+				bizix_core::Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			ensure!(
+				bizix_core::Pallet::<T>::is_deployable(&proposal.status),
+				Error::<T>::ProposalNotDeployable
+			);
+
+			let deployment_id = NextDeploymentId::<T>::get();
+			NextDeploymentId::<T>::put(deployment_id.saturating_add(1));
+
+			let now = <frame_system::Pallet<T>>::block_number();
+			Deployments::<T>::insert(
+				deployment_id,
+				Deployment {
+					customer: customer.clone(),
+					proposal_id,
+					template_id: proposal.template_id.clone(),
+					resources,
+					provider: None,
+					status: DeploymentStatus::Requested,
+					requested_at: now,
+					updated_at: now,
+					billed_until: now,
+					grace_until: None,
+				},
+			);
+			DeploymentsByCustomer::<T>::insert(&customer, deployment_id, ());
+
+			Self::deposit_event(Event::DeploymentRequested {
+				deployment_id,
+				customer,
+				proposal_id,
+				template_id: proposal.template_id,
+				resources,
+			});
+			Ok(())
+		}
+
+		/// Furnizorul preia o cerere și începe crearea instanței.
+		#[pallet::call_index(1)]
+		#[pallet::weight(10_000)]
+		pub fn accept_deployment(origin: OriginFor<T>, deployment_id: u32) -> DispatchResult {
+			let provider = ensure_signed(origin)?;
+
+			Deployments::<T>::try_mutate(deployment_id, |maybe_deployment| -> DispatchResult {
+				let deployment = maybe_deployment.as_mut().ok_or(Error::<T>::DeploymentNotFound)?;
+				ensure!(
+					pallet_providers::Pallet::<T>::is_eligible(
+						&provider,
+						&deployment.template_id,
+						&deployment.resources
+					),
+					Error::<T>::NotEligibleProvider
+				);
+				Self::set_status(deployment_id, deployment, DeploymentStatus::Provisioning)?;
+				pallet_providers::Pallet::<T>::reserve_capacity(&provider, &deployment.resources)?;
+				deployment.provider = Some(provider.clone());
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::DeploymentAccepted { deployment_id, provider });
+			Ok(())
+		}
+
+		/// Furnizorul asignat raportează progresul instalării.
+		#[pallet::call_index(2)]
+		#[pallet::weight(10_000)]
+		pub fn report_status(
+			origin: OriginFor<T>,
+			deployment_id: u32,
+			status: DeploymentStatus,
+		) -> DispatchResult {
+			let provider = ensure_signed(origin)?;
+
+			Deployments::<T>::try_mutate(deployment_id, |maybe_deployment| -> DispatchResult {
+				let deployment = maybe_deployment.as_mut().ok_or(Error::<T>::DeploymentNotFound)?;
+				ensure!(deployment.provider == Some(provider), Error::<T>::NotAssignedProvider);
+				// Suspendarea și repornirea după suspendare țin de facturare, nu de furnizor
+				ensure!(
+					status != DeploymentStatus::Suspended
+						&& (status != DeploymentStatus::Running
+							|| deployment.status == DeploymentStatus::Provisioning),
+					Error::<T>::InvalidStatusTransition
+				);
+				Self::set_status(deployment_id, deployment, status)?;
+
+				// Prima instanță pornită marchează propunerea ca instalată
+				if status == DeploymentStatus::Running {
+					bizix_core::Pallet::<T>::note_deployed(deployment.proposal_id)?;
+					RanVersions::<T>::insert(&deployment.customer, deployment.proposal_id, ());
+					Self::start_billing(deployment_id, deployment)?;
+				}
+				Ok(())
+			})
+		}
+
+		/// Clientul oprește instanța sau renunță la o cerere încă nepornită.
+		#[pallet::call_index(3)]
+		#[pallet::weight(10_000)]
+		pub fn stop_deployment(origin: OriginFor<T>, deployment_id: u32) -> DispatchResult {
+			let customer = ensure_signed(origin)?;
+
+			Deployments::<T>::try_mutate(deployment_id, |maybe_deployment| -> DispatchResult {
+				let deployment = maybe_deployment.as_mut().ok_or(Error::<T>::DeploymentNotFound)?;
+				ensure!(deployment.customer == customer, Error::<T>::NotCustomer);
+				// Perioada restantă și cea începută se plătesc înainte ca fondurile să poată fi
+				// retrase
+				if deployment.status == DeploymentStatus::Running {
+					Self::settle_partial_period(deployment_id, deployment);
+				}
+				Self::set_status(deployment_id, deployment, DeploymentStatus::Stopped)
+			})
+		}
+
+		/// Rezervă fonduri din care se plătesc instanțele clientului.
+		#[pallet::call_index(4)]
+		#[pallet::weight(10_000)]
+		pub fn top_up(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			CurrencyOf::<T>::reserve(&who, amount).map_err(|_| Error::<T>::InsufficientFunds)?;
+			BillingBalance::<T>::mutate(&who, |balance| *balance = balance.saturating_add(amount));
+
+			Self::deposit_event(Event::BillingFundsAdded { who, amount });
+			Ok(())
+		}
+
+		#[pallet::call_index(5)]
+		#[pallet::weight(10_000)]
+		pub fn withdraw_funds(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			BillingBalance::<T>::try_mutate(&who, |balance| -> DispatchResult {
+				ensure!(*balance >= amount, Error::<T>::InsufficientBillingBalance);
+				*balance = balance.saturating_sub(amount);
+				Ok(())
+			})?;
+			CurrencyOf::<T>::unreserve(&who, amount);
+
+			Self::deposit_event(Event::BillingFundsWithdrawn { who, amount });
+			Ok(())
+		}
+
+		/// Stabilește (sau elimină, cu `None`) prețul fix al unui template.
+		#[pallet::call_index(6)]
+		#[pallet::weight(10_000)]
+		pub fn set_template_price(
+			origin: OriginFor<T>,
+			template_id: T::ProxmoxTemplateID,
+			price: Option<BalanceOf<T>>,
+		) -> DispatchResult {
+			T::TechnicalCommittee::ensure_origin(origin)?;
+
+			match price {
+				Some(price) => TemplatePrices::<T>::insert(&template_id, price),
+				None => TemplatePrices::<T>::remove(&template_id),
+			}
+
+			Self::deposit_event(Event::TemplatePriceSet { template_id, price });
+			Ok(())
+		}
+
+		/// Repornește o instanță suspendată, dacă fondurile acoperă cel puțin o perioadă.
+		#[pallet::call_index(7)]
+		#[pallet::weight(10_000)]
+		pub fn resume_deployment(origin: OriginFor<T>, deployment_id: u32) -> DispatchResult {
+			let customer = ensure_signed(origin)?;
+
+			Deployments::<T>::try_mutate(deployment_id, |maybe_deployment| -> DispatchResult {
+				let deployment = maybe_deployment.as_mut().ok_or(Error::<T>::DeploymentNotFound)?;
+				ensure!(deployment.customer == customer, Error::<T>::NotCustomer);
+				ensure!(
+					deployment.status == DeploymentStatus::Suspended,
+					Error::<T>::InvalidStatusTransition
+				);
+				ensure!(
+					BillingBalance::<T>::get(&customer)
+						>= Self::price_of(&deployment.template_id, &deployment.resources),
+					Error::<T>::InsufficientBillingBalance
+				);
+
+				Self::set_status(deployment_id, deployment, DeploymentStatus::Running)?;
+				Self::start_billing(deployment_id, deployment)
+			})
+		}
+
+		/// Notează o versiune rulată de client. O notă nouă o înlocuiește pe cea veche.
+		#[pallet::call_index(8)]
+		#[pallet::weight(10_000)]
+		pub fn rate_application(
+			origin: OriginFor<T>,
+			proposal_id: u32,
+			score: u8,
+			// CID-ul recenziei, în formă text
+			review: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!((1..=MAX_RATING).contains(&score), Error::<T>::InvalidRating);
+			ensure!(RanVersions::<T>::contains_key(&who, proposal_id), Error::<T>::NeverRan);
+			let proposal =
+				bizix_core::Proposals::<T>::get(proposal_id).ok_or(Error::<T>::ProposalNotFound)?;
+			let review = review.map(|cid| bizix_core::Pallet::<T>::parse_cid(&cid)).transpose()?;
+
+			let previous = Ratings::<T>::get(proposal_id, &who).map(|rating| rating.score);
+			RatingSummaries::<T>::mutate(&proposal.name, |summary| {
+				match previous {
+					Some(old) => summary.total = summary.total.saturating_sub(old.into()),
+					None => summary.count = summary.count.saturating_add(1),
+				}
+				summary.total = summary.total.saturating_add(score.into());
+			});
+			Ratings::<T>::insert(
+				proposal_id,
+				&who,
+				Rating {
+					score,
+					review: review.clone(),
+					rated_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::ApplicationRated { proposal_id, who, score, review });
+			Ok(())
+		}
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			Self::bill_due_deployments(now)
+		}
+	}
+
+	// Implementare separată pentru metodele interne
+	impl<T: Config> Pallet<T> {
+		/// Tabelul tranzițiilor permise; `Stopped` și `Failed` sunt finale.
+		pub fn can_transition(from: DeploymentStatus, to: DeploymentStatus) -> bool {
+			use DeploymentStatus::*;
+			matches!(
+				(from, to),
+				(Requested, Provisioning)
+					| (Requested, Stopped)
+					| (Provisioning, Running)
+					| (Provisioning, Failed)
+					| (Provisioning, Stopped)
+					| (Running, Suspended)
+					| (Running, Stopped)
+					| (Running, Failed)
+					| (Suspended, Running)
+					| (Suspended, Stopped)
+			)
+		}
+
+		/// Prețul unei perioade de facturare: prețul fix al template-ului plus resursele cerute.
+		/// Memoria se facturează pe GB început.
+		pub fn price_of(template_id: &T::ProxmoxTemplateID, resources: &Resources) -> BalanceOf<T> {
+			let ram_gb = resources.ram_mb.saturating_add(1023) / 1024;
+			TemplatePrices::<T>::get(template_id)
+				.unwrap_or_else(Zero::zero)
+				.saturating_add(T::PricePerVcpu::get().saturating_mul(resources.vcpu.into()))
+				.saturating_add(T::PricePerGbRam::get().saturating_mul(ram_gb.into()))
+				.saturating_add(T::PricePerGbDisk::get().saturating_mul(resources.disk_gb.into()))
+		}
+
+		/// Facturarea pornește din blocul curent; timpul petrecut suspendat nu se plătește.
+		fn start_billing(deployment_id: u32, deployment: &mut Deployment<T>) -> DispatchResult {
+			let now = <frame_system::Pallet<T>>::block_number();
+			deployment.billed_until = now;
+			deployment.grace_until = None;
+			Self::schedule_billing(now.saturating_add(T::BillingPeriod::get()), deployment_id)?;
+			Ok(())
+		}
+
+		/// Programează facturarea la primul bloc începând cu `earliest` care mai are loc, căutând
+		/// în cel mult `MAX_BILLING_SCHEDULE_BLOCKS` blocuri.
+		fn schedule_billing(
+			earliest: BlockNumberFor<T>,
+			deployment_id: u32,
+		) -> Result<BlockNumberFor<T>, Error<T>> {
+			let mut block = earliest;
+			for _ in 0..MAX_BILLING_SCHEDULE_BLOCKS {
+				if BillingQueue::<T>::try_append(block, deployment_id).is_ok() {
+					return Ok(block);
+				}
+				block = block.saturating_add(One::one());
+			}
+			Err(Error::<T>::BillingQueueFull)
+		}
+
+		fn bill_due_deployments(now: BlockNumberFor<T>) -> Weight {
+			let db = T::DbWeight::get();
+			let mut weight = db.reads_writes(1, 1);
+
+			let due = BillingQueue::<T>::take(now);
+			if due.is_empty() {
+				return weight;
+			}
+
+			for deployment_id in due {
+				Deployments::<T>::mutate(deployment_id, |maybe_deployment| {
+					// Instanțele oprite între timp nu se mai facturează
+					if let Some(deployment) = maybe_deployment {
+						if deployment.status == DeploymentStatus::Running {
+							Self::bill(deployment_id, deployment, now);
+						}
+					}
+				});
+				// Instanța, furnizorul, fondurile clientului și cele două conturi, iar la
+				// reprogramare coada de facturare
+				weight = weight
+					.saturating_add(db.reads_writes(5 + MAX_BILLING_SCHEDULE_BLOCKS as u64, 5));
+			}
+
+			weight
+		}
+
+		/// Plătește furnizorului o perioadă din fondurile rezervate ale clientului sau, dacă
+		/// acestea nu ajung, pornește perioada de grație și apoi suspendă instanța.
+		fn bill(deployment_id: u32, deployment: &mut Deployment<T>, now: BlockNumberFor<T>) {
+			let Some(provider) = deployment.provider.clone() else { return };
+			let customer = deployment.customer.clone();
+			let amount = Self::price_of(&deployment.template_id, &deployment.resources);
+			let period_start = deployment.billed_until;
+			let period_end = period_start.saturating_add(T::BillingPeriod::get());
+
+			// Un furnizor suspendat sau retras nu este plătit; clientul nu datorează perioada
+			if !pallet_providers::Pallet::<T>::is_active(&provider) {
+				deployment.billed_until = period_end;
+				deployment.grace_until = None;
+				Self::reschedule(deployment_id, deployment, period_end, now);
+				Self::deposit_event(Event::BillingSkipped {
+					deployment_id,
+					provider,
+					period_start,
+					period_end,
+				});
+				return;
+			}
+
+			if Self::charge(&customer, &provider, amount) {
+				deployment.billed_until = period_end;
+				deployment.grace_until = None;
+				Self::reschedule(deployment_id, deployment, period_end, now);
+
+				Self::deposit_event(Event::DeploymentBilled {
+					deployment_id,
+					customer,
+					provider,
+					template_id: deployment.template_id.clone(),
+					resources: deployment.resources,
+					period_start,
+					period_end,
+					amount,
+				});
+				return;
+			}
+
+			match deployment.grace_until {
+				Some(grace_until) if now >= grace_until => {
+					let _ =
+						Self::set_status(deployment_id, deployment, DeploymentStatus::Suspended);
+					deployment.grace_until = None;
+					Self::deposit_event(Event::DeploymentSuspended {
+						deployment_id,
+						customer,
+						amount_due: amount,
+					});
+				},
+				_ => {
+					let grace_until = now.saturating_add(T::GracePeriod::get());
+					deployment.grace_until = Some(grace_until);
+					Self::deposit_event(Event::PaymentOverdue {
+						deployment_id,
+						customer,
+						amount_due: amount,
+						grace_until,
+					});
+					Self::reschedule(deployment_id, deployment, grace_until, now);
+				},
+			}
+		}
+
+		/// Mută `amount` din fondurile rezervate ale clientului la furnizor. Nu mută nimic dacă
+		/// suma nu este rezervată în întregime, ca o plată parțială să nu fie cerută din nou.
+		fn charge(customer: &T::AccountId, provider: &T::AccountId, amount: BalanceOf<T>) -> bool {
+			if BillingBalance::<T>::get(customer) < amount
+				|| CurrencyOf::<T>::reserved_balance(customer) < amount
+			{
+				return false;
+			}
+			let remaining = CurrencyOf::<T>::repatriate_reserved(
+				customer,
+				provider,
+				amount,
+				BalanceStatus::Free,
+			)
+			.unwrap_or(amount);
+			let moved = amount.saturating_sub(remaining);
+			BillingBalance::<T>::mutate(customer, |balance| {
+				*balance = balance.saturating_sub(moved)
+			});
+			remaining.is_zero()
+		}
+
+		/// Reprogramează facturarea după `at`. Dacă coada este plină, instanța se suspendă;
+		/// clientul o poate reporni mai târziu.
+		fn reschedule(
+			deployment_id: u32,
+			deployment: &mut Deployment<T>,
+			at: BlockNumberFor<T>,
+			now: BlockNumberFor<T>,
+		) {
+			if Self::schedule_billing(at.max(now.saturating_add(One::one())), deployment_id)
+				.is_err()
+			{
+				let _ = Self::set_status(deployment_id, deployment, DeploymentStatus::Suspended);
+				deployment.grace_until = None;
+			}
+		}
+
+		/// Plătește furnizorului timpul scurs de la ultima facturare, cât permit fondurile
+		/// clientului: întâi perioada restantă din perioada de grație, integral, apoi partea
+		/// scursă din perioada curentă.
+		fn settle_partial_period(deployment_id: u32, deployment: &mut Deployment<T>) {
+			let Some(provider) = deployment.provider.clone() else { return };
+			if !pallet_providers::Pallet::<T>::is_active(&provider) {
+				return;
+			}
+			let now = <frame_system::Pallet<T>>::block_number();
+			let price = Self::price_of(&deployment.template_id, &deployment.resources);
+			let customer = deployment.customer.clone();
+
+			let overdue_end = deployment.billed_until.saturating_add(T::BillingPeriod::get());
+			if now >= overdue_end && Self::charge(&customer, &provider, price) {
+				Self::note_billed(deployment_id, deployment, &provider, overdue_end, price);
+			}
+
+			let elapsed = now.saturating_sub(deployment.billed_until);
+			if elapsed.is_zero() {
+				return;
+			}
+			let share = Perbill::from_rational(elapsed, T::BillingPeriod::get());
+			// Fără fonduri suficiente se plătește cât a rămas rezervat
+			let amount = (share * price)
+				.min(BillingBalance::<T>::get(&customer))
+				.min(CurrencyOf::<T>::reserved_balance(&customer));
+			if amount.is_zero() || !Self::charge(&customer, &provider, amount) {
+				return;
+			}
+			Self::note_billed(deployment_id, deployment, &provider, now, amount);
+		}
+
+		/// Marchează instanța ca facturată până la `period_end`.
+		fn note_billed(
+			deployment_id: u32,
+			deployment: &mut Deployment<T>,
+			provider: &T::AccountId,
+			period_end: BlockNumberFor<T>,
+			amount: BalanceOf<T>,
+		) {
+			let period_start = sp_std::mem::replace(&mut deployment.billed_until, period_end);
+			Self::deposit_event(Event::DeploymentBilled {
+				deployment_id,
+				customer: deployment.customer.clone(),
+				provider: provider.clone(),
+				template_id: deployment.template_id.clone(),
+				resources: deployment.resources,
+				period_start,
+				period_end,
+				amount,
+			});
+		}
+
+		fn set_status(
+			deployment_id: u32,
+			deployment: &mut Deployment<T>,
+			to: DeploymentStatus,
+		) -> DispatchResult {
+			let from = deployment.status;
+			ensure!(Self::can_transition(from, to), Error::<T>::InvalidStatusTransition);
+
+			// Instanța nu mai rulează, resursele furnizorului se eliberează
+			if matches!(to, DeploymentStatus::Stopped | DeploymentStatus::Failed) {
+				if let Some(provider) = &deployment.provider {
+					pallet_providers::Pallet::<T>::release_capacity(
+						provider,
+						&deployment.resources,
+					);
+				}
+			}
+			deployment.status = to;
+			deployment.updated_at = <frame_system::Pallet<T>>::block_number();
+			Self::deposit_event(Event::DeploymentStatusChanged { deployment_id, from, to });
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,146 @@
+use crate as pallet_deployments;
+use frame_support::{
+	derive_impl, parameter_types,
+	traits::{ConstU16, ConstU32, ConstU64, SortedMembers},
+	BoundedVec,
+};
+use frame_system::EnsureRoot;
+use sp_core::H256;
+use sp_runtime::{
+	traits::{BlakeTwo256, IdentityLookup},
+	BuildStorage, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test
+	{
+		System: frame_system,
+		Balances: pallet_balances,
+		BizixCore: bizix_core,
+		ProviderRegistry: pallet_providers,
+		BizixDeployments: pallet_deployments,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig as frame_system::DefaultConfig)]
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Nonce = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Block = Block;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ConstU16<42>;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type RuntimeEvent = RuntimeEvent;
+	type DustRemoval = ();
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type WeightInfo = ();
+	type FreezeIdentifier = ();
+	type MaxFreezes = ();
+	type RuntimeHoldReason = ();
+	type RuntimeFreezeReason = ();
+}
+
+parameter_types! {
+	pub const ProposalPrice: u64 = 100;
+	pub const RejectionSlash: Perbill = Perbill::from_percent(20);
+	pub const ApprovalThreshold: bizix_core::VoteThreshold =
+		bizix_core::VoteThreshold::Proportion(Perbill::from_percent(50));
+	pub Committee: Vec<u64> = vec![2, 3, 4, 5];
+}
+
+pub struct CommitteeMembers;
+impl SortedMembers<u64> for CommitteeMembers {
+	fn sorted_members() -> Vec<u64> {
+		Committee::get()
+	}
+}
+
+impl bizix_core::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = ();
+	type IPFSAddress = BoundedVec<u8, ConstU32<64>>;
+	type ApplicationName = BoundedVec<u8, ConstU32<32>>;
+	type ApplicationVersion = BoundedVec<u8, ConstU32<16>>;
+	type ProxmoxTemplateID = u32;
+	type TechnicalCommittee = EnsureRoot<u64>;
+	type Currency = Balances;
+	type ProposalPrice = ProposalPrice;
+	type RejectionSlash = RejectionSlash;
+	type Slashed = ();
+	type CommitteeMembers = CommitteeMembers;
+	type ApprovalThreshold = ApprovalThreshold;
+	type VotingPeriod = ConstU64<10>;
+	type MaxAutoClosuresPerBlock = ConstU32<2>;
+	type MaxVoters = ConstU32<4>;
+	type MaxReleases = ConstU32<3>;
+	type MinReviews = ConstU32<0>;
+	type MaxAuditors = ConstU32<2>;
+	type AuthorityId = bizix_core::testing::TestAuthorityId;
+	type Attesters = CommitteeMembers;
+}
+
+bizix_core::impl_offchain_test_types!(Test, RuntimeCall);
+
+impl pallet_providers::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type TemplateId = u32;
+	type ManagerOrigin = EnsureRoot<u64>;
+	type Slashed = ();
+	type ProviderBond = ConstU64<500>;
+	type MaxEndpointLength = ConstU32<64>;
+	type MaxRegionLength = ConstU32<16>;
+	type MaxTemplates = ConstU32<4>;
+}
+
+impl pallet_deployments::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type PricePerVcpu = ConstU64<3>;
+	type PricePerGbRam = ConstU64<2>;
+	type PricePerGbDisk = ConstU64<1>;
+	type BillingPeriod = ConstU64<10>;
+	type GracePeriod = ConstU64<5>;
+	type MaxBillingsPerBlock = ConstU32<2>;
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(1, 1_000), (2, 1_000), (3, 1_000), (10, 1_000), (11, 1_000)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	// Go past genesis block so events get deposited
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
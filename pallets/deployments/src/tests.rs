@@ -0,0 +1,650 @@
+use crate::{
+	mock::*, BillingBalance, BillingQueue, DeploymentStatus, Deployments, DeploymentsByCustomer,
+	Error, Event, RatingSummaries, RatingSummary, Resources, MAX_BILLING_SCHEDULE_BLOCKS,
+};
+use bizix_core::ProposalStatusEnum;
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Hooks, ReservableCurrency},
+	BoundedVec,
+};
+
+const RESOURCES: Resources = Resources { vcpu: 2, ram_mb: 4_096, disk_gb: 40 };
+
+fn register_provider(who: u64, template_id: u32) {
+	assert_ok!(ProviderRegistry::register_provider(
+		RuntimeOrigin::signed(who),
+		BoundedVec::truncate_from(b"https://pve.example.ro:8006".to_vec()),
+		BoundedVec::truncate_from(b"ro-buc".to_vec()),
+		Resources { vcpu: 16, ram_mb: 32_768, disk_gb: 500 },
+		BoundedVec::truncate_from(vec![template_id]),
+	));
+}
+
+fn approved_proposal() -> u32 {
+	let proposal_id = BizixCore::current_proposal_id();
+	assert_ok!(BizixCore::submit_proposal(
+		RuntimeOrigin::signed(1),
+		b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+		BoundedVec::truncate_from(b"wordpress".to_vec()),
+		BoundedVec::truncate_from(b"1.0.0".to_vec()),
+		7,
+	));
+	assert_ok!(BizixCore::approve_proposal(RuntimeOrigin::root(), proposal_id));
+	proposal_id
+}
+
+#[test]
+fn request_records_template_and_resources() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			RESOURCES
+		));
+
+		let deployment = Deployments::<Test>::get(0).unwrap();
+		assert_eq!(deployment.customer, 2);
+		assert_eq!(deployment.template_id, 7);
+		assert_eq!(deployment.resources, RESOURCES);
+		assert_eq!(deployment.status, DeploymentStatus::Requested);
+		assert!(DeploymentsByCustomer::<Test>::contains_key(2, 0));
+		System::assert_last_event(
+			Event::DeploymentRequested {
+				deployment_id: 0,
+				customer: 2,
+				proposal_id,
+				template_id: 7,
+				resources: RESOURCES,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn only_approved_proposals_can_be_deployed() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = BizixCore::current_proposal_id();
+		assert_ok!(BizixCore::submit_proposal(
+			RuntimeOrigin::signed(1),
+			b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec(),
+			BoundedVec::truncate_from(b"wordpress".to_vec()),
+			BoundedVec::truncate_from(b"1.0.0".to_vec()),
+			7,
+		));
+
+		assert_noop!(
+			BizixDeployments::request_deployment(RuntimeOrigin::signed(2), proposal_id, RESOURCES),
+			Error::<Test>::ProposalNotDeployable
+		);
+		assert_noop!(
+			BizixDeployments::request_deployment(RuntimeOrigin::signed(2), 99, RESOURCES),
+			Error::<Test>::ProposalNotFound
+		);
+		let proposal_id = approved_proposal();
+		assert_noop!(
+			BizixDeployments::request_deployment(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				Resources { vcpu: 0, ..RESOURCES }
+			),
+			Error::<Test>::InvalidResources
+		);
+	});
+}
+
+#[test]
+fn provider_reports_progress() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+		register_provider(10, 7);
+		register_provider(11, 7);
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			RESOURCES
+		));
+
+		assert_noop!(
+			BizixDeployments::accept_deployment(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::NotEligibleProvider
+		);
+		assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 0));
+		assert_noop!(
+			BizixDeployments::report_status(
+				RuntimeOrigin::signed(11),
+				0,
+				DeploymentStatus::Running
+			),
+			Error::<Test>::NotAssignedProvider
+		);
+		assert_ok!(BizixDeployments::report_status(
+			RuntimeOrigin::signed(10),
+			0,
+			DeploymentStatus::Running
+		));
+
+		let deployment = Deployments::<Test>::get(0).unwrap();
+		assert_eq!(deployment.provider, Some(10));
+		assert_eq!(deployment.status, DeploymentStatus::Running);
+		// Prima instanță pornită mută propunerea în `Deployed`
+		assert_eq!(
+			bizix_core::Proposals::<Test>::get(proposal_id).unwrap().status,
+			ProposalStatusEnum::Deployed
+		);
+	});
+}
+
+#[test]
+fn providers_must_support_template_and_capacity() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+		register_provider(10, 8);
+		register_provider(11, 7);
+
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			Resources { vcpu: 64, ..RESOURCES }
+		));
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			RESOURCES
+		));
+
+		// Furnizorul 10 nu suportă template-ul 7, iar 11 nu are destule procesoare pentru prima cerere
+		assert_noop!(
+			BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 1),
+			Error::<Test>::NotEligibleProvider
+		);
+		assert_noop!(
+			BizixDeployments::accept_deployment(RuntimeOrigin::signed(11), 0),
+			Error::<Test>::NotEligibleProvider
+		);
+
+		assert_ok!(ProviderRegistry::suspend_provider(RuntimeOrigin::root(), 11));
+		assert_noop!(
+			BizixDeployments::accept_deployment(RuntimeOrigin::signed(11), 1),
+			Error::<Test>::NotEligibleProvider
+		);
+	});
+}
+
+#[test]
+fn accepted_deployments_reserve_provider_capacity() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+		// Loc pentru exact două instanțe
+		assert_ok!(ProviderRegistry::register_provider(
+			RuntimeOrigin::signed(10),
+			BoundedVec::truncate_from(b"https://pve.example.ro:8006".to_vec()),
+			BoundedVec::truncate_from(b"ro-buc".to_vec()),
+			Resources { vcpu: 4, ram_mb: 8_192, disk_gb: 80 },
+			BoundedVec::truncate_from(vec![7]),
+		));
+		for _ in 0..3 {
+			assert_ok!(BizixDeployments::request_deployment(
+				RuntimeOrigin::signed(2),
+				proposal_id,
+				RESOURCES
+			));
+		}
+
+		assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 0));
+		assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 1));
+		assert_eq!(
+			ProviderRegistry::used_capacity(10),
+			Resources { vcpu: 4, ram_mb: 8_192, disk_gb: 80 }
+		);
+		assert_noop!(
+			BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 2),
+			Error::<Test>::NotEligibleProvider
+		);
+
+		// Oprirea eliberează resursele
+		assert_ok!(BizixDeployments::stop_deployment(RuntimeOrigin::signed(2), 0));
+		assert_eq!(ProviderRegistry::used_capacity(10), RESOURCES);
+		assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 2));
+
+		// La fel și eșecul instalării
+		assert_ok!(BizixDeployments::report_status(
+			RuntimeOrigin::signed(10),
+			1,
+			DeploymentStatus::Failed
+		));
+		assert_eq!(ProviderRegistry::used_capacity(10), RESOURCES);
+	});
+}
+
+#[test]
+fn stopped_and_failed_are_final() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+		register_provider(10, 7);
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			RESOURCES
+		));
+		assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 0));
+		assert_ok!(BizixDeployments::report_status(
+			RuntimeOrigin::signed(10),
+			0,
+			DeploymentStatus::Failed
+		));
+
+		assert_noop!(
+			BizixDeployments::report_status(
+				RuntimeOrigin::signed(10),
+				0,
+				DeploymentStatus::Running
+			),
+			Error::<Test>::InvalidStatusTransition
+		);
+		assert_noop!(
+			BizixDeployments::stop_deployment(RuntimeOrigin::signed(2), 0),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+#[test]
+fn customer_can_stop_own_deployment() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			RESOURCES
+		));
+
+		assert_noop!(
+			BizixDeployments::stop_deployment(RuntimeOrigin::signed(3), 0),
+			Error::<Test>::NotCustomer
+		);
+		assert_ok!(BizixDeployments::stop_deployment(RuntimeOrigin::signed(2), 0));
+		assert_eq!(Deployments::<Test>::get(0).unwrap().status, DeploymentStatus::Stopped);
+		register_provider(10, 7);
+		// O cerere oprită nu mai poate fi preluată
+		assert_noop!(
+			BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 0),
+			Error::<Test>::InvalidStatusTransition
+		);
+	});
+}
+
+// 2 vCPU * 3 + 4 GB RAM * 2 + 40 GB disc * 1
+const PRICE: u64 = 54;
+
+/// Pornește o instanță a clientului 2 la furnizorul 10, în blocul curent.
+fn running_deployment() -> u32 {
+	let proposal_id = approved_proposal();
+	register_provider(10, 7);
+	start_deployment(proposal_id)
+}
+
+fn start_deployment(proposal_id: u32) -> u32 {
+	let deployment_id = BizixDeployments::next_deployment_id();
+	assert_ok!(BizixDeployments::request_deployment(
+		RuntimeOrigin::signed(2),
+		proposal_id,
+		RESOURCES
+	));
+	assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), deployment_id));
+	assert_ok!(BizixDeployments::report_status(
+		RuntimeOrigin::signed(10),
+		deployment_id,
+		DeploymentStatus::Running
+	));
+	deployment_id
+}
+
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		BizixDeployments::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn price_combines_template_and_resources() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(BizixDeployments::price_of(&7, &RESOURCES), PRICE);
+		// Memoria se rotunjește în sus la GB
+		assert_eq!(
+			BizixDeployments::price_of(&7, &Resources { ram_mb: 4_097, ..RESOURCES }),
+			PRICE + 2
+		);
+
+		assert_ok!(BizixDeployments::set_template_price(RuntimeOrigin::root(), 7, Some(6)));
+		assert_eq!(BizixDeployments::price_of(&7, &RESOURCES), PRICE + 6);
+		assert_ok!(BizixDeployments::set_template_price(RuntimeOrigin::root(), 7, None));
+		assert_eq!(BizixDeployments::price_of(&7, &RESOURCES), PRICE);
+	});
+}
+
+#[test]
+fn running_instances_are_billed_every_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 200));
+		let deployment_id = running_deployment();
+
+		run_to_block(11);
+
+		assert_eq!(BillingBalance::<Test>::get(2), 200 - PRICE);
+		assert_eq!(Balances::reserved_balance(2), 200 - PRICE);
+		assert_eq!(Balances::free_balance(10), 1_000 - 500 + PRICE);
+		System::assert_has_event(
+			Event::DeploymentBilled {
+				deployment_id,
+				customer: 2,
+				provider: 10,
+				template_id: 7,
+				resources: RESOURCES,
+				period_start: 1,
+				period_end: 11,
+				amount: PRICE,
+			}
+			.into(),
+		);
+
+		run_to_block(21);
+		assert_eq!(BillingBalance::<Test>::get(2), 200 - 2 * PRICE);
+		assert_eq!(Deployments::<Test>::get(deployment_id).unwrap().billed_until, 21);
+	});
+}
+
+#[test]
+fn unpaid_instances_are_suspended_after_grace_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 30));
+		let deployment_id = running_deployment();
+
+		run_to_block(11);
+		System::assert_has_event(
+			Event::PaymentOverdue {
+				deployment_id,
+				customer: 2,
+				amount_due: PRICE,
+				grace_until: 16,
+			}
+			.into(),
+		);
+		assert_eq!(
+			Deployments::<Test>::get(deployment_id).unwrap().status,
+			DeploymentStatus::Running
+		);
+
+		run_to_block(16);
+		assert_eq!(
+			Deployments::<Test>::get(deployment_id).unwrap().status,
+			DeploymentStatus::Suspended
+		);
+		System::assert_has_event(
+			Event::DeploymentSuspended { deployment_id, customer: 2, amount_due: PRICE }.into(),
+		);
+		// Fondurile insuficiente nu au fost atinse
+		assert_eq!(BillingBalance::<Test>::get(2), 30);
+
+		assert_noop!(
+			BizixDeployments::resume_deployment(RuntimeOrigin::signed(2), deployment_id),
+			Error::<Test>::InsufficientBillingBalance
+		);
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 100));
+		assert_ok!(BizixDeployments::resume_deployment(RuntimeOrigin::signed(2), deployment_id));
+		assert_eq!(BillingQueue::<Test>::get(26), vec![deployment_id]);
+	});
+}
+
+#[test]
+fn paying_during_grace_period_avoids_suspension() {
+	new_test_ext().execute_with(|| {
+		let deployment_id = running_deployment();
+
+		run_to_block(11);
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), PRICE));
+		run_to_block(16);
+
+		let deployment = Deployments::<Test>::get(deployment_id).unwrap();
+		assert_eq!(deployment.status, DeploymentStatus::Running);
+		assert_eq!(deployment.grace_until, None);
+		assert_eq!(deployment.billed_until, 11);
+		assert_eq!(BillingBalance::<Test>::get(2), 0);
+	});
+}
+
+#[test]
+fn billing_is_bounded_per_block() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 500));
+		let proposal_id = approved_proposal();
+		register_provider(10, 7);
+		let ids: Vec<u32> = (0..3).map(|_| start_deployment(proposal_id)).collect();
+
+		System::set_block_number(11);
+		BizixDeployments::on_initialize(11);
+
+		assert_eq!(BillingBalance::<Test>::get(2), 500 - 2 * PRICE);
+		assert_eq!(BillingQueue::<Test>::get(12), vec![ids[2]]);
+	});
+}
+
+#[test]
+fn billing_fails_when_the_queue_is_full() {
+	new_test_ext().execute_with(|| {
+		let proposal_id = approved_proposal();
+		register_provider(10, 7);
+		for block in 11..11 + MAX_BILLING_SCHEDULE_BLOCKS as u64 {
+			BillingQueue::<Test>::insert(block, BoundedVec::truncate_from(vec![100, 101]));
+		}
+		assert_ok!(BizixDeployments::request_deployment(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			RESOURCES
+		));
+		assert_ok!(BizixDeployments::accept_deployment(RuntimeOrigin::signed(10), 0));
+
+		assert_noop!(
+			BizixDeployments::report_status(
+				RuntimeOrigin::signed(10),
+				0,
+				DeploymentStatus::Running
+			),
+			Error::<Test>::BillingQueueFull
+		);
+	});
+}
+
+#[test]
+fn partially_reserved_funds_are_not_charged() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 100));
+		let deployment_id = running_deployment();
+		// Rezerva clientului a scăzut în afara paletei
+		Balances::unreserve(&2, 60);
+
+		run_to_block(11);
+
+		// Nimic nu s-a mutat, deci nimic nu se cere a doua oară
+		assert_eq!(Balances::reserved_balance(2), 40);
+		assert_eq!(BillingBalance::<Test>::get(2), 100);
+		assert_eq!(Balances::free_balance(10), 1_000 - 500);
+		System::assert_has_event(
+			Event::PaymentOverdue {
+				deployment_id,
+				customer: 2,
+				amount_due: PRICE,
+				grace_until: 16,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn stopping_bills_the_started_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 200));
+		let deployment_id = running_deployment();
+
+		run_to_block(6);
+		assert_ok!(BizixDeployments::stop_deployment(RuntimeOrigin::signed(2), deployment_id));
+
+		// Jumătate de perioadă
+		assert_eq!(BillingBalance::<Test>::get(2), 200 - PRICE / 2);
+		assert_eq!(Balances::free_balance(10), 1_000 - 500 + PRICE / 2);
+		System::assert_has_event(
+			Event::DeploymentBilled {
+				deployment_id,
+				customer: 2,
+				provider: 10,
+				template_id: 7,
+				resources: RESOURCES,
+				period_start: 1,
+				period_end: 6,
+				amount: PRICE / 2,
+			}
+			.into(),
+		);
+
+		// Instanța oprită nu se mai facturează
+		run_to_block(11);
+		assert_eq!(BillingBalance::<Test>::get(2), 200 - PRICE / 2);
+	});
+}
+
+#[test]
+fn stopping_during_grace_period_bills_the_overdue_period() {
+	new_test_ext().execute_with(|| {
+		// Un preț divizibil cu perioada de facturare
+		assert_ok!(BizixDeployments::set_template_price(RuntimeOrigin::root(), 7, Some(6)));
+		let deployment_id = running_deployment();
+		run_to_block(11);
+		assert_eq!(Deployments::<Test>::get(deployment_id).unwrap().grace_until, Some(16));
+
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 200));
+		run_to_block(13);
+		assert_ok!(BizixDeployments::stop_deployment(RuntimeOrigin::signed(2), deployment_id));
+
+		// Perioada restantă integral, apoi două blocuri din perioada curentă
+		System::assert_has_event(
+			Event::DeploymentBilled {
+				deployment_id,
+				customer: 2,
+				provider: 10,
+				template_id: 7,
+				resources: RESOURCES,
+				period_start: 1,
+				period_end: 11,
+				amount: PRICE + 6,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			Event::DeploymentBilled {
+				deployment_id,
+				customer: 2,
+				provider: 10,
+				template_id: 7,
+				resources: RESOURCES,
+				period_start: 11,
+				period_end: 13,
+				amount: 12,
+			}
+			.into(),
+		);
+		assert_eq!(BillingBalance::<Test>::get(2), 200 - (PRICE + 6) - 12);
+		assert_eq!(Balances::free_balance(10), 1_000 - 500 + (PRICE + 6) + 12);
+	});
+}
+
+#[test]
+fn suspended_providers_are_not_paid() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 200));
+		let deployment_id = running_deployment();
+		assert_ok!(ProviderRegistry::suspend_provider(RuntimeOrigin::root(), 10));
+
+		run_to_block(11);
+
+		assert_eq!(BillingBalance::<Test>::get(2), 200);
+		assert_eq!(Balances::free_balance(10), 1_000 - 500);
+		System::assert_has_event(
+			Event::BillingSkipped { deployment_id, provider: 10, period_start: 1, period_end: 11 }
+				.into(),
+		);
+		// Facturarea continuă după reactivarea furnizorului
+		assert_eq!(BillingQueue::<Test>::get(21), vec![deployment_id]);
+		assert_ok!(ProviderRegistry::resume_provider(RuntimeOrigin::root(), 10));
+		run_to_block(21);
+		assert_eq!(BillingBalance::<Test>::get(2), 200 - PRICE);
+	});
+}
+
+#[test]
+fn billing_funds_can_be_withdrawn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(BizixDeployments::top_up(RuntimeOrigin::signed(2), 100));
+		assert_noop!(
+			BizixDeployments::withdraw_funds(RuntimeOrigin::signed(2), 101),
+			Error::<Test>::InsufficientBillingBalance
+		);
+		assert_ok!(BizixDeployments::withdraw_funds(RuntimeOrigin::signed(2), 60));
+
+		assert_eq!(BillingBalance::<Test>::get(2), 40);
+		assert_eq!(Balances::reserved_balance(2), 40);
+	});
+}
+
+#[test]
+fn only_users_who_ran_a_version_can_rate_it() {
+	new_test_ext().execute_with(|| {
+		let deployment_id = running_deployment();
+		let proposal_id = Deployments::<Test>::get(deployment_id).unwrap().proposal_id;
+		let name: <Test as bizix_core::Config>::ApplicationName =
+			BoundedVec::truncate_from(b"wordpress".to_vec());
+
+		assert_noop!(
+			BizixDeployments::rate_application(RuntimeOrigin::signed(3), proposal_id, 5, None),
+			Error::<Test>::NeverRan
+		);
+		for score in [0, 6] {
+			assert_noop!(
+				BizixDeployments::rate_application(
+					RuntimeOrigin::signed(2),
+					proposal_id,
+					score,
+					None
+				),
+				Error::<Test>::InvalidRating
+			);
+		}
+
+		// Instanța oprită nu anulează dreptul de a nota
+		assert_ok!(BizixDeployments::stop_deployment(RuntimeOrigin::signed(2), deployment_id));
+		assert_ok!(BizixDeployments::rate_application(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			4,
+			Some(b"QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".to_vec())
+		));
+		assert!(BizixDeployments::ratings(proposal_id, 2).unwrap().review.is_some());
+		assert_eq!(RatingSummaries::<Test>::get(&name), RatingSummary { count: 1, total: 4 });
+
+		// O notă nouă o înlocuiește pe cea veche
+		assert_ok!(BizixDeployments::rate_application(
+			RuntimeOrigin::signed(2),
+			proposal_id,
+			2,
+			None
+		));
+		assert_eq!(RatingSummaries::<Test>::get(&name), RatingSummary { count: 1, total: 2 });
+		System::assert_last_event(
+			Event::ApplicationRated { proposal_id, who: 2, score: 2, review: None }.into(),
+		);
+	});
+}
@@ -0,0 +1,28 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+	pub trait ProvidersApi<AccountId, Balance> where
+		AccountId: Codec,
+		Balance: Codec,
+	{
+		/// Furnizorii activi care pot instala template-ul Proxmox dat.
+		fn eligible_providers(template_id: u32) -> Vec<ProviderInfo<AccountId, Balance>>;
+	}
+}
+
+// Oglindește `pallet_providers::Provider`, cu câmpurile aduse la `Vec<u8>`
+#[derive(Encode, Decode, TypeInfo)]
+pub struct ProviderInfo<AccountId, Balance> {
+	pub account: AccountId,
+	pub endpoint: Vec<u8>,
+	pub region: Vec<u8>,
+	pub vcpu: u32,
+	pub ram_mb: u32,
+	pub disk_gb: u32,
+	pub templates: Vec<u32>,
+	pub bond: Balance,
+}
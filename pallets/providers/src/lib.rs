@@ -0,0 +1,365 @@
+//! # Providers Pallet
+//!
+//! Registry of the operators running the Proxmox clusters that host BiziX applications. An
+//! operator bonds funds, announces an endpoint, a region, the capacity available for new
+//! instances and the Proxmox templates it can provision. The `ManagerOrigin` can suspend
+//! misbehaving providers and slash their bond; suspended providers are not eligible for new
+//! deployments.
+//!
+//! Run `cargo doc --package pallet-providers --open` to view this pallet's documentation.
+
+// We make sure this pallet uses `no_std` for compiling to Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, Imbalance, OnUnbalanced, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Saturating;
+	use sp_std::vec::Vec;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		type Currency: ReservableCurrency<Self::AccountId>;
+		// Identificatorul unui template Proxmox
+		type TemplateId: Parameter + Member + MaxEncodedLen;
+		// Originea care suspendă, reactivează și penalizează furnizorii
+		type ManagerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+		// Unde ajung garanțiile confiscate
+		type Slashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		// Garanția rezervată la înregistrarea unui furnizor
+		#[pallet::constant]
+		type ProviderBond: Get<BalanceOf<Self>>;
+		#[pallet::constant]
+		type MaxEndpointLength: Get<u32>;
+		#[pallet::constant]
+		type MaxRegionLength: Get<u32>;
+		// Numărul maxim de template-uri Proxmox anunțate de un furnizor
+		#[pallet::constant]
+		type MaxTemplates: Get<u32>;
+	}
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	// Resurse de calcul: cerute de o instanță sau disponibile la un furnizor
+	#[derive(
+		Encode, Decode, Clone, Copy, Default, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen,
+	)]
+	pub struct Resources {
+		pub vcpu: u32,
+		pub ram_mb: u32,
+		pub disk_gb: u32,
+	}
+
+	impl Resources {
+		pub fn is_empty(&self) -> bool {
+			self.vcpu == 0 || self.ram_mb == 0 || self.disk_gb == 0
+		}
+
+		pub fn fits_in(&self, capacity: &Resources) -> bool {
+			self.vcpu <= capacity.vcpu &&
+				self.ram_mb <= capacity.ram_mb &&
+				self.disk_gb <= capacity.disk_gb
+		}
+
+		pub fn saturating_add(&self, other: &Resources) -> Resources {
+			Resources {
+				vcpu: self.vcpu.saturating_add(other.vcpu),
+				ram_mb: self.ram_mb.saturating_add(other.ram_mb),
+				disk_gb: self.disk_gb.saturating_add(other.disk_gb),
+			}
+		}
+
+		pub fn saturating_sub(&self, other: &Resources) -> Resources {
+			Resources {
+				vcpu: self.vcpu.saturating_sub(other.vcpu),
+				ram_mb: self.ram_mb.saturating_sub(other.ram_mb),
+				disk_gb: self.disk_gb.saturating_sub(other.disk_gb),
+			}
+		}
+	}
+
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	pub enum ProviderStatus {
+		Active,
+		// Suspendat de comitet; nu poate prelua instalări noi
+		Suspended,
+	}
+
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+	#[scale_info(skip_type_params(T))]
+	#[codec(mel_bound())]
+	pub struct Provider<T: Config> {
+		pub endpoint: BoundedVec<u8, T::MaxEndpointLength>,
+		pub region: BoundedVec<u8, T::MaxRegionLength>,
+		pub capacity: Resources,
+		pub templates: BoundedVec<T::TemplateId, T::MaxTemplates>,
+		pub bond: BalanceOf<T>,
+		pub status: ProviderStatus,
+		pub registered_at: BlockNumberFor<T>,
+	}
+
+	impl<T: Config> Provider<T> {
+		pub fn supports(&self, template_id: &T::TemplateId) -> bool {
+			self.templates.contains(template_id)
+		}
+	}
+
+	// Stocare
+	#[pallet::storage]
+	#[pallet::getter(fn providers)]
+	pub type Providers<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, Provider<T>>;
+
+	// Resursele ocupate de instanțele preluate de fiecare furnizor
+	#[pallet::storage]
+	#[pallet::getter(fn used_capacity)]
+	pub type UsedCapacity<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Resources, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		ProviderRegistered { provider: T::AccountId, bond: BalanceOf<T> },
+		ProviderUpdated { provider: T::AccountId },
+		ProviderUnregistered { provider: T::AccountId, bond: BalanceOf<T> },
+		ProviderSuspended { provider: T::AccountId },
+		ProviderResumed { provider: T::AccountId },
+		ProviderSlashed { provider: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		AlreadyRegistered,
+		ProviderNotFound,
+		InsufficientFunds,
+		// Toate resursele anunțate trebuie să fie nenule
+		InvalidCapacity,
+		NoTemplates,
+		ProviderSuspended,
+		ProviderNotSuspended,
+		// Resursele libere ale furnizorului nu ajung
+		InsufficientCapacity,
+		// Furnizorul are încă instalări preluate
+		HasDeployments,
+	}
+
+	// Funcții apelabile
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::call_index(0)]
+		#[pallet::weight(10_000)]
+		pub fn register_provider(
+			origin: OriginFor<T>,
+			endpoint: BoundedVec<u8, T::MaxEndpointLength>,
+			region: BoundedVec<u8, T::MaxRegionLength>,
+			capacity: Resources,
+			templates: BoundedVec<T::TemplateId, T::MaxTemplates>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Providers::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
+			ensure!(!capacity.is_empty(), Error::<T>::InvalidCapacity);
+			ensure!(!templates.is_empty(), Error::<T>::NoTemplates);
+
+			let bond = T::ProviderBond::get();
+			T::Currency::reserve(&who, bond).map_err(|_| Error::<T>::InsufficientFunds)?;
+
+			Providers::<T>::insert(
+				&who,
+				Provider {
+					endpoint,
+					region,
+					capacity,
+					templates,
+					bond,
+					status: ProviderStatus::Active,
+					registered_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::ProviderRegistered { provider: who, bond });
+			Ok(())
+		}
+
+		/// Actualizează datele anunțate de furnizor; garanția și statusul rămân neschimbate.
+		#[pallet::call_index(1)]
+		#[pallet::weight(10_000)]
+		pub fn update_provider(
+			origin: OriginFor<T>,
+			endpoint: BoundedVec<u8, T::MaxEndpointLength>,
+			region: BoundedVec<u8, T::MaxRegionLength>,
+			capacity: Resources,
+			templates: BoundedVec<T::TemplateId, T::MaxTemplates>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!capacity.is_empty(), Error::<T>::InvalidCapacity);
+			ensure!(!templates.is_empty(), Error::<T>::NoTemplates);
+
+			Providers::<T>::try_mutate(&who, |maybe_provider| -> DispatchResult {
+				let provider = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+				provider.endpoint = endpoint;
+				provider.region = region;
+				provider.capacity = capacity;
+				provider.templates = templates;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProviderUpdated { provider: who });
+			Ok(())
+		}
+
+		/// Retragerea din registru eliberează garanția. Un furnizor suspendat nu se poate
+		/// retrage până nu este reactivat, iar unul cu instalări preluate până nu le oprește.
+		#[pallet::call_index(2)]
+		#[pallet::weight(10_000)]
+		pub fn unregister_provider(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let provider = Providers::<T>::get(&who).ok_or(Error::<T>::ProviderNotFound)?;
+			ensure!(provider.status == ProviderStatus::Active, Error::<T>::ProviderSuspended);
+			ensure!(
+				UsedCapacity::<T>::get(&who) == Resources::default(),
+				Error::<T>::HasDeployments
+			);
+
+			Providers::<T>::remove(&who);
+			T::Currency::unreserve(&who, provider.bond);
+
+			Self::deposit_event(Event::ProviderUnregistered { provider: who, bond: provider.bond });
+			Ok(())
+		}
+
+		#[pallet::call_index(3)]
+		#[pallet::weight(10_000)]
+		pub fn suspend_provider(origin: OriginFor<T>, provider: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Providers::<T>::try_mutate(&provider, |maybe_provider| -> DispatchResult {
+				let record = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+				ensure!(record.status == ProviderStatus::Active, Error::<T>::ProviderSuspended);
+				record.status = ProviderStatus::Suspended;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProviderSuspended { provider });
+			Ok(())
+		}
+
+		#[pallet::call_index(4)]
+		#[pallet::weight(10_000)]
+		pub fn resume_provider(origin: OriginFor<T>, provider: T::AccountId) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			Providers::<T>::try_mutate(&provider, |maybe_provider| -> DispatchResult {
+				let record = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+				ensure!(record.status == ProviderStatus::Suspended, Error::<T>::ProviderNotSuspended);
+				record.status = ProviderStatus::Active;
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::ProviderResumed { provider });
+			Ok(())
+		}
+
+		/// Confiscă până la `amount` din garanția furnizorului.
+		#[pallet::call_index(5)]
+		#[pallet::weight(10_000)]
+		pub fn slash_provider(
+			origin: OriginFor<T>,
+			provider: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::ManagerOrigin::ensure_origin(origin)?;
+
+			let slashed = Providers::<T>::try_mutate(
+				&provider,
+				|maybe_provider| -> Result<BalanceOf<T>, DispatchError> {
+					let record = maybe_provider.as_mut().ok_or(Error::<T>::ProviderNotFound)?;
+					let (imbalance, _) =
+						T::Currency::slash_reserved(&provider, amount.min(record.bond));
+					let slashed = imbalance.peek();
+					record.bond = record.bond.saturating_sub(slashed);
+					T::Slashed::on_unbalanced(imbalance);
+					Ok(slashed)
+				},
+			)?;
+
+			Self::deposit_event(Event::ProviderSlashed { provider, amount: slashed });
+			Ok(())
+		}
+	}
+
+	// Implementare separată pentru metodele interne
+	impl<T: Config> Pallet<T> {
+		/// Furnizorul este înregistrat și nu este suspendat.
+		pub fn is_active(who: &T::AccountId) -> bool {
+			Providers::<T>::get(who)
+				.map_or(false, |provider| provider.status == ProviderStatus::Active)
+		}
+
+		/// Un furnizor activ care suportă template-ul și are destulă capacitate.
+		pub fn is_eligible(
+			who: &T::AccountId,
+			template_id: &T::TemplateId,
+			resources: &Resources,
+		) -> bool {
+			Providers::<T>::get(who).map_or(false, |provider| {
+				provider.status == ProviderStatus::Active &&
+					provider.supports(template_id) &&
+					resources.fits_in(&Self::free_capacity(who, &provider))
+			})
+		}
+
+		/// Capacitatea anunțată minus resursele ocupate de instanțele preluate.
+		pub fn free_capacity(who: &T::AccountId, provider: &Provider<T>) -> Resources {
+			provider.capacity.saturating_sub(&UsedCapacity::<T>::get(who))
+		}
+
+		/// Ocupă resursele unei instanțe preluate de furnizor.
+		pub fn reserve_capacity(who: &T::AccountId, resources: &Resources) -> DispatchResult {
+			let provider = Providers::<T>::get(who).ok_or(Error::<T>::ProviderNotFound)?;
+			ensure!(
+				resources.fits_in(&Self::free_capacity(who, &provider)),
+				Error::<T>::InsufficientCapacity
+			);
+			UsedCapacity::<T>::mutate(who, |used| *used = used.saturating_add(resources));
+			Ok(())
+		}
+
+		/// Eliberează resursele unei instanțe oprite sau eșuate.
+		pub fn release_capacity(who: &T::AccountId, resources: &Resources) {
+			UsedCapacity::<T>::mutate_exists(who, |maybe_used| {
+				let used = maybe_used.unwrap_or_default().saturating_sub(resources);
+				*maybe_used = (used != Resources::default()).then_some(used);
+			});
+		}
+
+		/// Furnizorii activi care pot instala template-ul dat.
+		pub fn eligible_providers(template_id: &T::TemplateId) -> Vec<(T::AccountId, Provider<T>)> {
+			Providers::<T>::iter()
+				.filter(|(_, provider)| {
+					provider.status == ProviderStatus::Active && provider.supports(template_id)
+				})
+				.collect()
+		}
+	}
+}
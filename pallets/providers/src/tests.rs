@@ -0,0 +1,171 @@
+use crate::{mock::*, Error, Event, ProviderStatus, Providers, Resources};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use sp_runtime::DispatchError;
+
+const CAPACITY: Resources = Resources { vcpu: 32, ram_mb: 65_536, disk_gb: 2_000 };
+
+fn register(who: u64, templates: Vec<u32>) {
+	assert_ok!(ProviderRegistry::register_provider(
+		RuntimeOrigin::signed(who),
+		BoundedVec::truncate_from(b"https://pve.example.ro:8006".to_vec()),
+		BoundedVec::truncate_from(b"ro-buc".to_vec()),
+		CAPACITY,
+		BoundedVec::truncate_from(templates),
+	));
+}
+
+#[test]
+fn registration_reserves_bond() {
+	new_test_ext().execute_with(|| {
+		register(1, vec![7, 8]);
+
+		assert_eq!(Balances::reserved_balance(1), 500);
+		let provider = Providers::<Test>::get(1).unwrap();
+		assert_eq!(provider.capacity, CAPACITY);
+		assert_eq!(provider.status, ProviderStatus::Active);
+		System::assert_last_event(Event::ProviderRegistered { provider: 1, bond: 500 }.into());
+
+		assert_noop!(
+			ProviderRegistry::register_provider(
+				RuntimeOrigin::signed(1),
+				BoundedVec::new(),
+				BoundedVec::new(),
+				CAPACITY,
+				BoundedVec::truncate_from(vec![7]),
+			),
+			Error::<Test>::AlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn registration_requires_capacity_templates_and_funds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProviderRegistry::register_provider(
+				RuntimeOrigin::signed(1),
+				BoundedVec::new(),
+				BoundedVec::new(),
+				Resources { disk_gb: 0, ..CAPACITY },
+				BoundedVec::truncate_from(vec![7]),
+			),
+			Error::<Test>::InvalidCapacity
+		);
+		assert_noop!(
+			ProviderRegistry::register_provider(
+				RuntimeOrigin::signed(1),
+				BoundedVec::new(),
+				BoundedVec::new(),
+				CAPACITY,
+				BoundedVec::new(),
+			),
+			Error::<Test>::NoTemplates
+		);
+		assert_noop!(
+			ProviderRegistry::register_provider(
+				RuntimeOrigin::signed(42),
+				BoundedVec::new(),
+				BoundedVec::new(),
+				CAPACITY,
+				BoundedVec::truncate_from(vec![7]),
+			),
+			Error::<Test>::InsufficientFunds
+		);
+	});
+}
+
+#[test]
+fn committee_can_suspend_and_resume() {
+	new_test_ext().execute_with(|| {
+		register(1, vec![7]);
+
+		assert_noop!(
+			ProviderRegistry::suspend_provider(RuntimeOrigin::signed(2), 1),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(ProviderRegistry::suspend_provider(RuntimeOrigin::root(), 1));
+		assert!(!ProviderRegistry::is_eligible(&1, &7, &Resources::default()));
+		// Garanția rămâne blocată cât timp furnizorul este suspendat
+		assert_noop!(
+			ProviderRegistry::unregister_provider(RuntimeOrigin::signed(1)),
+			Error::<Test>::ProviderSuspended
+		);
+
+		assert_ok!(ProviderRegistry::resume_provider(RuntimeOrigin::root(), 1));
+		assert_ok!(ProviderRegistry::unregister_provider(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert!(Providers::<Test>::get(1).is_none());
+	});
+}
+
+#[test]
+fn eligible_providers_are_filtered_by_template() {
+	new_test_ext().execute_with(|| {
+		register(1, vec![7, 8]);
+		register(2, vec![8]);
+		register(3, vec![7]);
+		assert_ok!(ProviderRegistry::suspend_provider(RuntimeOrigin::root(), 3));
+
+		let mut eligible: Vec<u64> =
+			ProviderRegistry::eligible_providers(&7).into_iter().map(|(who, _)| who).collect();
+		eligible.sort();
+		assert_eq!(eligible, vec![1]);
+
+		let mut eligible: Vec<u64> =
+			ProviderRegistry::eligible_providers(&8).into_iter().map(|(who, _)| who).collect();
+		eligible.sort();
+		assert_eq!(eligible, vec![1, 2]);
+
+		assert!(!ProviderRegistry::is_eligible(
+			&1,
+			&7,
+			&Resources { vcpu: 64, ..Resources::default() }
+		));
+	});
+}
+
+#[test]
+fn providers_with_deployments_cannot_unregister() {
+	new_test_ext().execute_with(|| {
+		register(1, vec![7]);
+		let resources = Resources { vcpu: 2, ram_mb: 4_096, disk_gb: 40 };
+		assert_ok!(ProviderRegistry::reserve_capacity(&1, &resources));
+
+		assert_noop!(
+			ProviderRegistry::unregister_provider(RuntimeOrigin::signed(1)),
+			Error::<Test>::HasDeployments
+		);
+
+		ProviderRegistry::release_capacity(&1, &resources);
+		assert_ok!(ProviderRegistry::unregister_provider(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+	});
+}
+
+#[test]
+fn manager_can_slash_the_bond() {
+	new_test_ext().execute_with(|| {
+		register(1, vec![7]);
+
+		assert_noop!(
+			ProviderRegistry::slash_provider(RuntimeOrigin::signed(2), 1, 100),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ProviderRegistry::slash_provider(RuntimeOrigin::root(), 2, 100),
+			Error::<Test>::ProviderNotFound
+		);
+
+		assert_ok!(ProviderRegistry::slash_provider(RuntimeOrigin::root(), 1, 100));
+		assert_eq!(Providers::<Test>::get(1).unwrap().bond, 400);
+		assert_eq!(Balances::reserved_balance(1), 400);
+		assert_eq!(Balances::free_balance(1), 500);
+		System::assert_last_event(Event::ProviderSlashed { provider: 1, amount: 100 }.into());
+
+		// Nu se confiscă mai mult decât garanția
+		assert_ok!(ProviderRegistry::slash_provider(RuntimeOrigin::root(), 1, 1_000));
+		assert_eq!(Providers::<Test>::get(1).unwrap().bond, 0);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::ProviderSlashed { provider: 1, amount: 400 }.into());
+	});
+}
@@ -49,6 +49,8 @@ pub use sp_core::Encode;
 /// Import the bizix pallets.
 pub use bizix_core;
 pub use pallet_company_registry;
+pub use pallet_deployments;
+pub use pallet_providers;
 
 // Imports the treasury pallet
 use frame_system::EnsureRoot;
@@ -60,7 +62,7 @@ pub use pallet_treasury;
 use pallet_collective::{self, PrimeDefaultVote};
 
 use pallet_identity::legacy::IdentityInfo;
-use pallet_identity::RegistrarIndex;
+use pallet_identity::{Data, Judgement, RegistrarIndex};
 
 // Definește constantele pentru unități
 pub const MILLIUNIT: Balance = 1_000_000_000_000_000; // 10^15
@@ -121,10 +123,11 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 80000,
+	spec_version: 80025,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Se incrementează la orice schimbare a codificării apelurilor (argumente, indici)
+	transaction_version: 6,
 	state_version: 1,
 };
 
@@ -167,10 +170,35 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 
 	// Tipuri de date pentru bizix
-    pub const MaxIpfsAddressLength: u32 = 256;
+	// CID-urile sunt păstrate în forma binară (cel mult 39 de octeți pentru tipurile permise)
+    pub const MaxIpfsAddressLength: u32 = 64;
     pub const MaxAppNameLength: u32 = 128;
     pub const MaxAppVersionLength: u32 = 32;
 	pub const CompanyRegistryQueryFee: Balance = 1_000_000_000;
+	pub const CompanyRegistrarBond: Balance = 100 * UNITS;
+	pub const CompanyClaimDeposit: Balance = 10 * UNITS;
+	pub const CompanyClaimChallengePeriod: BlockNumber = 7 * DAYS;
+	pub const CompanyClaimResolutionPeriod: BlockNumber = 14 * DAYS;
+	pub const CompanyTransferOfferPeriod: BlockNumber = 3 * DAYS;
+	pub const CompanyMaxTransferExpiriesPerBlock: u32 = 50;
+	pub const CompanyMaxRevisions: u32 = 32;
+	pub const BizixProposalPrice: Balance = 100 * MILLIUNIT;
+	pub const BizixRejectionSlash: Perbill = Perbill::from_percent(20);
+	pub const BizixApprovalThreshold: bizix_core::VoteThreshold =
+		bizix_core::VoteThreshold::Proportion(Perbill::from_percent(50));
+	pub const BizixVotingPeriod: BlockNumber = 7 * DAYS;
+	pub const BizixMaxAutoClosuresPerBlock: u32 = 10;
+	pub const BizixMaxReleases: u32 = 64;
+	pub const BizixMinReviews: u32 = 2;
+	pub const BizixMaxAuditors: u32 = 32;
+	pub const ProviderBond: Balance = 1_000 * UNITS;
+	// Prețuri pe oră de funcționare
+	pub const DeploymentPricePerVcpu: Balance = 10 * MILLIUNIT;
+	pub const DeploymentPricePerGbRam: Balance = 5 * MILLIUNIT;
+	pub const DeploymentPricePerGbDisk: Balance = MILLIUNIT / 10;
+	pub const DeploymentBillingPeriod: BlockNumber = HOURS;
+	pub const DeploymentGracePeriod: BlockNumber = DAYS;
+	pub const DeploymentMaxBillingsPerBlock: u32 = 50;
 
 	pub const TechnicalMotionDuration: BlockNumber = 5 * DAYS;
     pub const TechnicalMaxProposals: u32 = 100;
@@ -287,20 +315,117 @@ impl pallet_sudo::Config for Runtime {
 impl bizix_core::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = bizix_core::weights::SubstrateWeight<Runtime>;
-    type IPFSAddress = Vec<u8>;
-    type ApplicationName = Vec<u8>;
-    type ApplicationVersion = Vec<u8>;
-    type ProposalPrice = Balance;
+    type IPFSAddress = BoundedVec<u8, MaxIpfsAddressLength>;
+    type ApplicationName = BoundedVec<u8, MaxAppNameLength>;
+    type ApplicationVersion = BoundedVec<u8, MaxAppVersionLength>;
     type ProxmoxTemplateID = u32;
-    type TechnicalCommittee = EnsureRoot<AccountId>;
-	//TO DO: type TechnicalCommittee = pallet_collective::EnsureMember<AccountId, TechnicalCollective>;
+	// Deciziile necesită cel puțin jumătate din membrii comitetului tehnic
+	type TechnicalCommittee =
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>;
 	type Currency = Balances;
+	type ProposalPrice = BizixProposalPrice;
+	type RejectionSlash = BizixRejectionSlash;
+	type Slashed = Treasury;
+	type CommitteeMembers = TechnicalCommitteeMembers;
+	type ApprovalThreshold = BizixApprovalThreshold;
+	type VotingPeriod = BizixVotingPeriod;
+	type MaxAutoClosuresPerBlock = BizixMaxAutoClosuresPerBlock;
+	// Votează doar membrii comitetului tehnic
+	type MaxVoters = TechnicalMaxMembers;
+	type MaxReleases = BizixMaxReleases;
+	// Doi membri ai comitetului recenzează fiecare propunere
+	type MinReviews = BizixMinReviews;
+	type MaxAuditors = BizixMaxAuditors;
+	type AuthorityId = bizix_core::crypto::AuthorityId;
+	// Atestările IPFS sunt trimise de nodurile membrilor comitetului tehnic
+	type Attesters = TechnicalCommitteeMembers;
+}
+
+// Tranzacții semnate trimise de offchain worker-ul bizix-core
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+	RuntimeCall: From<LocalCall>,
+{
+	fn create_transaction<C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>>(
+		call: RuntimeCall,
+		public: <Signature as Verify>::Signer,
+		account: AccountId,
+		nonce: Nonce,
+	) -> Option<(RuntimeCall, <UncheckedExtrinsic as sp_runtime::traits::Extrinsic>::SignaturePayload)> {
+		use sp_runtime::{traits::StaticLookup, SaturatedConversion};
+
+		let period = BlockHashCount::get()
+			.checked_next_power_of_two()
+			.map(|c| c / 2)
+			.unwrap_or(2) as u64;
+		let current_block = System::block_number().saturated_into::<u64>().saturating_sub(1);
+		let extra: SignedExtra = (
+			frame_system::CheckNonZeroSender::<Runtime>::new(),
+			frame_system::CheckSpecVersion::<Runtime>::new(),
+			frame_system::CheckTxVersion::<Runtime>::new(),
+			frame_system::CheckGenesis::<Runtime>::new(),
+			frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+			frame_system::CheckNonce::<Runtime>::from(nonce),
+			frame_system::CheckWeight::<Runtime>::new(),
+			pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+		);
+		let raw_payload = SignedPayload::new(call, extra).ok()?;
+		let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+		let address = <Runtime as frame_system::Config>::Lookup::unlookup(account);
+		let (call, extra, _) = raw_payload.deconstruct();
+		Some((call, (address, signature, extra)))
+	}
+}
+
+impl frame_system::offchain::SigningTypes for Runtime {
+	type Public = <Signature as Verify>::Signer;
+	type Signature = Signature;
+}
+
+impl<C> frame_system::offchain::SendTransactionTypes<C> for Runtime
+where
+	RuntimeCall: From<C>,
+{
+	type OverarchingCall = RuntimeCall;
+	type Extrinsic = UncheckedExtrinsic;
+}
+
+/// Expune membrii comitetului tehnic (lista din `pallet_collective` este deja sortată).
+pub struct TechnicalCommitteeMembers;
+impl frame_support::traits::SortedMembers<AccountId> for TechnicalCommitteeMembers {
+	fn sorted_members() -> Vec<AccountId> {
+		pallet_collective::Members::<Runtime, TechnicalCollective>::get()
+	}
+}
+
+impl pallet_providers::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type TemplateId = u32;
+	// La fel ca pentru bizix-core, jumătate din comitetul tehnic
+	type ManagerOrigin =
+		pallet_collective::EnsureProportionAtLeast<AccountId, TechnicalCollective, 1, 2>;
+	type Slashed = Treasury;
+	type ProviderBond = ProviderBond;
+	type MaxEndpointLength = ConstU32<256>;
+	type MaxRegionLength = ConstU32<32>;
+	type MaxTemplates = ConstU32<64>;
+}
+
+impl pallet_deployments::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type PricePerVcpu = DeploymentPricePerVcpu;
+	type PricePerGbRam = DeploymentPricePerGbRam;
+	type PricePerGbDisk = DeploymentPricePerGbDisk;
+	type BillingPeriod = DeploymentBillingPeriod;
+	type GracePeriod = DeploymentGracePeriod;
+	type MaxBillingsPerBlock = DeploymentMaxBillingsPerBlock;
 }
 
 impl pallet_company_registry::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = pallet_company_registry::weights::SubstrateWeight<Runtime>;
-    type CUI = u16;
+    type CUI = u64;
     type Denumire = BoundedVec<u8, ConstU32<128>>;
     type CodInmatriculare = BoundedVec<u8, ConstU32<32>>;
     type EUID = BoundedVec<u8, ConstU32<32>>;
@@ -308,6 +433,31 @@ impl pallet_company_registry::Config for Runtime {
     type AdresaCompleta = BoundedVec<u8, ConstU32<256>>;
     type Currency = Balances;
     type QueryFee = CompanyRegistryQueryFee;// cost interogare 1 token
+    type RegistrarOrigin = EnsureRoot<AccountId>;
+    type RegistrarBond = CompanyRegistrarBond;
+    type Slashed = Treasury;
+    type ClaimDeposit = CompanyClaimDeposit;
+    type ChallengePeriod = CompanyClaimChallengePeriod;
+    type ClaimResolutionPeriod = CompanyClaimResolutionPeriod;
+    type Identity = IdentityJudgement;
+    type TransferOfferPeriod = CompanyTransferOfferPeriod;
+    type MaxTransferExpiriesPerBlock = CompanyMaxTransferExpiriesPerBlock;
+    type MaxRevisions = CompanyMaxRevisions;
+}
+
+/// Identitate cu judecată pozitivă de la un registrator și numele legal cerut.
+pub struct IdentityJudgement;
+impl pallet_company_registry::IdentityVerifier<AccountId> for IdentityJudgement {
+	fn is_verified_as(who: &AccountId, name: &[u8]) -> bool {
+		let Some((registration, _)) = pallet_identity::IdentityOf::<Runtime>::get(who) else {
+			return false
+		};
+		let judged = registration.judgements.iter().any(|(_, judgement)| {
+			matches!(judgement, Judgement::KnownGood | Judgement::Reasonable)
+		});
+		judged &&
+			matches!(&registration.info.legal, Data::Raw(legal) if legal.eq_ignore_ascii_case(name))
+	}
 }
 
 // Implementarea modulului Paymaster
@@ -445,8 +595,7 @@ impl pallet_identity::Config for Runtime {
     // Greutăți pentru benchmark
     type WeightInfo = pallet_identity::weights::SubstrateWeight<Runtime>;
 }
-
-impl pallet_collective::Config for Runtime {
+impl pallet_collective::Config<TechnicalCollective> for Runtime {
     type RuntimeOrigin = RuntimeOrigin;
     type Proposal = RuntimeCall;
     type RuntimeEvent = RuntimeEvent;
@@ -460,7 +609,6 @@ impl pallet_collective::Config for Runtime {
     type MaxProposalWeight = MaxCollectivesProposalWeight;
 }
 
-
 // Definește un tip pentru TechnicalCollective
 pub type TechnicalCollective = pallet_collective::Instance2;
 
@@ -515,10 +663,16 @@ mod runtime {
 	pub type Treasury = pallet_treasury;
 
 	#[runtime::pallet_index(10)]
-	pub type TechnicalCommittee = pallet_collective;
+	pub type TechnicalCommittee = pallet_collective<Instance2>;
 
 	#[runtime::pallet_index(11)]
 	pub type Identity = pallet_identity;
+
+	#[runtime::pallet_index(12)]
+	pub type Deployments = pallet_deployments;
+
+	#[runtime::pallet_index(13)]
+	pub type Providers = pallet_providers;
 }
 
 /// The address format for describing accounts.
@@ -543,7 +697,14 @@ pub type SignedExtra = (
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	bizix_core::migrations::v1::MigrateToV1<Runtime>,
+	bizix_core::migrations::v2::MigrateToV2<Runtime>,
+	bizix_core::migrations::v3::MigrateToV3<Runtime>,
+	pallet_company_registry::migrations::v1::MigrateToV1<Runtime>,
+	pallet_company_registry::migrations::v2::MigrateToV2<Runtime>,
+	pallet_company_registry::migrations::v3::MigrateToV3<Runtime>,
+);
 
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic =
@@ -575,6 +736,174 @@ mod benches {
 	);
 }
 
+// Conversia intrărilor din catalogul bizix-core în tipurile expuse prin runtime API
+fn bizix_application_info(
+	name: <Runtime as bizix_core::Config>::ApplicationName,
+	application: bizix_core::Application<Runtime>,
+) -> bizix_core_runtime_api::ApplicationInfo<AccountId, BlockNumber> {
+	// Notele sunt păstrate de paletul de instalări
+	let rating = pallet_deployments::RatingSummaries::<Runtime>::get(&name);
+	bizix_core_runtime_api::ApplicationInfo {
+		name: name.into(),
+		publisher: application.publisher,
+		releases: application
+			.releases
+			.into_iter()
+			.map(|release| bizix_core_runtime_api::ReleaseInfo {
+				proposal_id: release.proposal_id,
+				version: release.version.into(),
+				ipfs_address: release.ipfs_address.into(),
+				template_id: release.template_id,
+				approved_at: release.approved_at,
+				audited: BizixCore::is_audited(release.proposal_id),
+				audits: bizix_audits(release.proposal_id),
+			})
+			.collect(),
+		latest: application.latest,
+		rating_count: rating.count,
+		rating_total: rating.total,
+	}
+}
+
+fn bizix_audits(proposal_id: u32) -> Vec<bizix_core_runtime_api::AuditInfo<AccountId, BlockNumber>> {
+	BizixCore::audits_of(proposal_id)
+		.into_iter()
+		.map(|(auditor, attestation, valid)| bizix_core_runtime_api::AuditInfo {
+			auditor,
+			report: attestation.report.into(),
+			critical: attestation.severities.critical,
+			high: attestation.severities.high,
+			medium: attestation.severities.medium,
+			low: attestation.severities.low,
+			attested_at: attestation.attested_at,
+			expires_at: attestation.expires_at,
+			valid,
+		})
+		.collect()
+}
+
+fn bizix_proposal_status(
+	status: bizix_core::ProposalStatusEnum,
+) -> bizix_core_runtime_api::ProposalStatus {
+	use bizix_core::ProposalStatusEnum as S;
+	use bizix_core_runtime_api::ProposalStatus as R;
+	match status {
+		S::New => R::New,
+		S::InDiscussion => R::InDiscussion,
+		S::Approved => R::Approved,
+		S::Rejected => R::Rejected,
+		S::Withdrawn => R::Withdrawn,
+		S::Deployed => R::Deployed,
+		S::Deprecated => R::Deprecated,
+		S::Superseded => R::Superseded,
+		S::Revoked => R::Revoked,
+	}
+}
+
+fn bizix_status_from_api(
+	status: bizix_core_runtime_api::ProposalStatus,
+) -> bizix_core::ProposalStatusEnum {
+	use bizix_core::ProposalStatusEnum as S;
+	use bizix_core_runtime_api::ProposalStatus as R;
+	match status {
+		R::New => S::New,
+		R::InDiscussion => S::InDiscussion,
+		R::Approved => S::Approved,
+		R::Rejected => S::Rejected,
+		R::Withdrawn => S::Withdrawn,
+		R::Deployed => S::Deployed,
+		R::Deprecated => S::Deprecated,
+		R::Superseded => S::Superseded,
+		R::Revoked => S::Revoked,
+	}
+}
+
+fn bizix_proposal_info(
+	id: u32,
+	proposal: bizix_core::Proposal<Runtime>,
+) -> bizix_core_runtime_api::ProposalInfo<AccountId, Balance, BlockNumber> {
+	bizix_core_runtime_api::ProposalInfo {
+		id,
+		proposer: proposal.proposer,
+		ipfs_address: proposal.ipfs_address.into(),
+		name: proposal.name.into(),
+		version: proposal.version.into(),
+		template_id: proposal.template_id,
+		status: bizix_proposal_status(proposal.status),
+		deposit: proposal.deposit,
+		voting_end: proposal.voting_end,
+		status_history: BizixCore::status_history(id)
+			.into_iter()
+			.map(|(status, block)| (bizix_proposal_status(status), block))
+			.collect(),
+	}
+}
+
+fn bizix_proposal_page(
+	(proposals, next): (Vec<(u32, bizix_core::Proposal<Runtime>)>, Option<u32>),
+) -> bizix_core_runtime_api::ProposalPage<AccountId, Balance, BlockNumber> {
+	bizix_core_runtime_api::ProposalPage {
+		proposals: proposals
+			.into_iter()
+			.map(|(id, proposal)| bizix_proposal_info(id, proposal))
+			.collect(),
+		next,
+	}
+}
+
+fn provider_info(
+	account: AccountId,
+	provider: pallet_providers::Provider<Runtime>,
+) -> pallet_providers_rpc_runtime_api::ProviderInfo<AccountId, Balance> {
+	pallet_providers_rpc_runtime_api::ProviderInfo {
+		account,
+		endpoint: provider.endpoint.into(),
+		region: provider.region.into(),
+		vcpu: provider.capacity.vcpu,
+		ram_mb: provider.capacity.ram_mb,
+		disk_gb: provider.capacity.disk_gb,
+		templates: provider.templates.into(),
+		bond: provider.bond,
+	}
+}
+
+fn company_field_value(
+	value: pallet_company_registry::FieldValue<Runtime>,
+) -> (pallet_company_registry_rpc_runtime_api::CompanyField, Vec<u8>) {
+	use pallet_company_registry::FieldValue as V;
+	use pallet_company_registry_rpc_runtime_api::CompanyField as F;
+	match value {
+		V::Denumire(value) => (F::Denumire, value.into()),
+		V::CodInmatriculare(value) => (F::CodInmatriculare, value.into()),
+		V::Euid(value) => (F::Euid, value.into()),
+		V::StareFirma(value) => (F::StareFirma, value.into()),
+		V::AdresaCompleta(value) => (F::AdresaCompleta, value.into()),
+		V::Judet(value) => (F::Judet, value.into()),
+		V::Owner(account) => (F::Owner, account.encode()),
+	}
+}
+
+fn company_revision(
+	revision: pallet_company_registry::Revision<Runtime>,
+) -> pallet_company_registry_rpc_runtime_api::CompanyRevision<AccountId, BlockNumber> {
+	pallet_company_registry_rpc_runtime_api::CompanyRevision {
+		author: revision.author,
+		block: revision.block,
+		changes: revision
+			.changes
+			.into_iter()
+			.map(|change| {
+				let (field, new) = company_field_value(change.new);
+				pallet_company_registry_rpc_runtime_api::FieldChange {
+					field,
+					old: change.old.map(|old| company_field_value(old).1),
+					new,
+				}
+			})
+			.collect(),
+	}
+}
+
 impl_runtime_apis! {
 	impl sp_api::Core<Block> for Runtime {
 		fn version() -> RuntimeVersion {
@@ -743,14 +1072,99 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl bizix_core_runtime_api::BizixApi<Block> for Runtime {
-		fn get_value() -> u32 {
-			BizixCore::current_proposal_id()
+	impl bizix_core_runtime_api::BizixApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn proposal_deposit() -> Balance {
+			<Runtime as bizix_core::Config>::ProposalPrice::get()
+		}
+
+		fn application(name: Vec<u8>) -> Option<bizix_core_runtime_api::ApplicationInfo<AccountId, BlockNumber>> {
+			let name: <Runtime as bizix_core::Config>::ApplicationName = name.try_into().ok()?;
+			BizixCore::applications(&name).map(|application| bizix_application_info(name, application))
+		}
+
+		fn applications(start_after: Option<Vec<u8>>, limit: u32) -> Vec<bizix_core_runtime_api::ApplicationInfo<AccountId, BlockNumber>> {
+			let start_after = match start_after {
+				Some(name) => match name.try_into() {
+					Ok(name) => Some(name),
+					Err(_) => return Vec::new(),
+				},
+				None => None,
+			};
+			BizixCore::applications_page(start_after, limit)
+				.into_iter()
+				.map(|(name, application)| bizix_application_info(name, application))
+				.collect()
+		}
+
+		fn proposal(proposal_id: u32) -> Option<bizix_core_runtime_api::ProposalInfo<AccountId, Balance, BlockNumber>> {
+			BizixCore::proposals(proposal_id).map(|proposal| bizix_proposal_info(proposal_id, proposal))
+		}
+
+		fn proposals_by_status(
+			status: bizix_core_runtime_api::ProposalStatus,
+			start_after: Option<u32>,
+			limit: u32,
+		) -> bizix_core_runtime_api::ProposalPage<AccountId, Balance, BlockNumber> {
+			bizix_proposal_page(BizixCore::proposals_by_status(bizix_status_from_api(status), start_after, limit))
+		}
+
+		fn proposals_by_proposer(
+			proposer: AccountId,
+			start_after: Option<u32>,
+			limit: u32,
+		) -> bizix_core_runtime_api::ProposalPage<AccountId, Balance, BlockNumber> {
+			bizix_proposal_page(BizixCore::proposals_by_proposer(&proposer, start_after, limit))
+		}
+
+		fn votes(proposal_id: u32) -> Option<bizix_core_runtime_api::VoteInfo<AccountId>> {
+			BizixCore::votes(proposal_id).map(|record| bizix_core_runtime_api::VoteInfo {
+				ayes: record.ayes,
+				nays: record.nays,
+				threshold: record.threshold,
+				voters: record.voters.into(),
+			})
+		}
+
+		fn audits(proposal_id: u32) -> Vec<bizix_core_runtime_api::AuditInfo<AccountId, BlockNumber>> {
+			bizix_audits(proposal_id)
+		}
+
+		fn stats() -> bizix_core_runtime_api::Stats {
+			use bizix_core::ProposalStatusEnum as S;
+			let statuses = [
+				S::New,
+				S::InDiscussion,
+				S::Approved,
+				S::Rejected,
+				S::Withdrawn,
+				S::Deployed,
+				S::Deprecated,
+				S::Superseded,
+				S::Revoked,
+			];
+			bizix_core_runtime_api::Stats {
+				proposal_count: BizixCore::proposal_count(),
+				next_proposal_id: BizixCore::current_proposal_id(),
+				proposals_by_status: statuses
+					.into_iter()
+					.map(|status| (bizix_proposal_status(status.clone()), BizixCore::count_by_status(status)))
+					.collect(),
+				applications: BizixCore::application_count(),
+			}
+		}
+	}
+
+	impl pallet_providers_rpc_runtime_api::ProvidersApi<Block, AccountId, Balance> for Runtime {
+		fn eligible_providers(template_id: u32) -> Vec<pallet_providers_rpc_runtime_api::ProviderInfo<AccountId, Balance>> {
+			Providers::eligible_providers(&template_id)
+				.into_iter()
+				.map(|(account, provider)| provider_info(account, provider))
+				.collect()
 		}
 	}
 
-	impl pallet_company_registry_rpc_runtime_api::CompanyRegistryApi<Block, AccountId, Balance> for Runtime {
-		fn get_company_data(cui: u16, caller: AccountId) -> Option<pallet_company_registry_rpc_runtime_api::Company<AccountId>> {
+	impl pallet_company_registry_rpc_runtime_api::CompanyRegistryApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn get_company_data(cui: u64, caller: AccountId) -> Option<pallet_company_registry_rpc_runtime_api::Company<AccountId>> {
 			CompanyRegistry::get_company_data(cui.into(), caller).map(|company| 
 				pallet_company_registry_rpc_runtime_api::Company {
 					cui: company.cui.into(),
@@ -760,6 +1174,8 @@ impl_runtime_apis! {
 					stare_firma: company.stare_firma.into(),
 					adresa_completa: company.adresa_completa.into(),
 					owner: company.owner,
+					judet: company.judet.map(Into::into),
+					attested_by: company.attested_by,
 				}
 			)
 		}
@@ -768,11 +1184,11 @@ impl_runtime_apis! {
 			<Runtime as pallet_company_registry::Config>::QueryFee::get()
 		}
 
-		fn has_paid_for_company_data(caller: AccountId, cui: u16) -> bool {
+		fn has_paid_for_company_data(caller: AccountId, cui: u64) -> bool {
 			CompanyRegistry::has_paid_for_company_data(caller, cui.into())
 		}
 
-		fn get_company_data_if_paid(caller: AccountId, cui: u16) -> Option<pallet_company_registry_rpc_runtime_api::Company<AccountId>> {
+		fn get_company_data_if_paid(caller: AccountId, cui: u64) -> Option<pallet_company_registry_rpc_runtime_api::Company<AccountId>> {
 			if Self::has_paid_for_company_data(caller.clone(), cui) {
 				CompanyRegistry::get_company_data(cui.into(), caller).map(|company| 
 					pallet_company_registry_rpc_runtime_api::Company {
@@ -783,6 +1199,8 @@ impl_runtime_apis! {
 						stare_firma: company.stare_firma.into(),
 						adresa_completa: company.adresa_completa.into(),
 						owner: company.owner,
+						judet: company.judet.map(Into::into),
+						attested_by: company.attested_by,
 					}
 				)
 			} else {
@@ -790,6 +1208,21 @@ impl_runtime_apis! {
 			}
 		}
 
+		fn pending_transfers(account: AccountId) -> Vec<pallet_company_registry_rpc_runtime_api::TransferOffer<AccountId, BlockNumber>> {
+			CompanyRegistry::pending_transfers(&account)
+				.into_iter()
+				.map(|(cui, offer)| pallet_company_registry_rpc_runtime_api::TransferOffer {
+					cui,
+					from: offer.from,
+					to: offer.to,
+					expires_at: offer.expires_at,
+				})
+				.collect()
+		}
+
+		fn company_history(cui: u64) -> Vec<pallet_company_registry_rpc_runtime_api::CompanyRevision<AccountId, BlockNumber>> {
+			CompanyRegistry::company_history(cui).into_iter().map(company_revision).collect()
+		}
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]